@@ -0,0 +1,48 @@
+//! Benchmarks every registered day, parsing and solving both parts on its
+//! `data/YYYY/DD/input.txt`. The input is read into memory up front so that
+//! file system noise does not end up in the measurements.
+//!
+//! Compare before and after a change with criterion's baselines:
+//!
+//! ```sh
+//! cargo bench --bench days -- --save-baseline before 2023/17
+//! # ... optimize ...
+//! cargo bench --bench days -- --baseline before 2023/17
+//! ```
+
+extern crate lib;
+
+use std::fs;
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use lib::reader::Input;
+use lib::registry::get_entries;
+use lib::solution::Part;
+
+fn bench_days(c: &mut Criterion) {
+    let mut group = c.benchmark_group("days");
+    // Some days take seconds per run (e.g. 2023/25), so keep the default
+    // sample count from turning a full run into hours.
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_secs(1));
+
+    let parts = [Part::One, Part::Two];
+    for entry in get_entries() {
+        let path = format!("data/{}/{:02}/input.txt", entry.year, entry.day);
+        let text = match fs::read_to_string(&path) {
+            Result::Ok(text) => text,
+            Result::Err(_) => continue,
+        };
+        let input = Input::Text(text);
+        let id = format!("{}/{:02}", entry.year, entry.day);
+        group.bench_function(id, |b| {
+            b.iter(|| (entry.solve)(&input, &[], &parts).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
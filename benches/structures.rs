@@ -0,0 +1,107 @@
+//! Micro-benchmarks for the shared data structures in `lib`.
+
+extern crate lib;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use lib::linked_lists::SinglyLinkedList;
+use lib::trie::Trie;
+
+const WORDS: [&str; 19] = [
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+    "seven", "eight", "nine",
+];
+
+const LIST_SIZE: i64 = 1024;
+
+fn build_trie() -> Trie {
+    let mut trie = Trie::new();
+    for (value, word) in WORDS.iter().enumerate() {
+        trie.insert(word.to_string(), value as i64);
+    }
+    return trie;
+}
+
+fn bench_trie(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trie");
+
+    group.bench_function("insert", |b| {
+        b.iter(build_trie);
+    });
+
+    let trie = build_trie();
+    group.bench_function("get_child", |b| {
+        b.iter(|| {
+            let mut found = 0;
+            for word in WORDS.iter() {
+                let mut pointer = trie.get_root();
+                for char in word.chars() {
+                    pointer = trie.get_child(&pointer, black_box(char)).unwrap();
+                }
+                if trie.get_value(&pointer).is_some() {
+                    found += 1;
+                }
+            }
+            return found;
+        });
+    });
+
+    group.finish();
+}
+
+fn build_list() -> SinglyLinkedList<i64> {
+    let mut list = SinglyLinkedList::new();
+    for i in 0..LIST_SIZE {
+        list.insert_tail(i);
+    }
+    return list;
+}
+
+fn bench_singly_linked_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("singly_linked_list");
+
+    group.bench_function("insert_head", |b| {
+        b.iter(|| {
+            let mut list = SinglyLinkedList::new();
+            for i in 0..LIST_SIZE {
+                list.insert_head(black_box(i));
+            }
+            return list;
+        });
+    });
+
+    group.bench_function("insert_tail", |b| {
+        b.iter(build_list);
+    });
+
+    group.bench_function("pop", |b| {
+        b.iter_batched(
+            build_list,
+            |mut list| {
+                let mut total = 0;
+                while let Option::Some(value) = list.pop() {
+                    total += value;
+                }
+                return total;
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    let list = build_list();
+    group.bench_function("traverse", |b| {
+        b.iter(|| {
+            let mut total = 0;
+            let mut node = list.get_head();
+            while let Option::Some(current) = node {
+                total += current.get_data();
+                node = current.next();
+            }
+            return total;
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_trie, bench_singly_linked_list);
+criterion_main!(benches);
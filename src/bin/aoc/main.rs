@@ -0,0 +1,363 @@
+extern crate lib;
+
+mod timing;
+
+use std::io;
+use std::process::ExitCode;
+
+use lib::reader::Input;
+use lib::registry::{get_entries, Entry};
+use lib::solution::{Answer, Part, Run, SolveError};
+use timing::Stats;
+
+const USAGE: &str = "\
+Usage: aoc [options] <selection>...
+
+Selections:
+  2023        every day of a year
+  2023/5      both parts of a single day
+  2023/5/1    a single part of a day
+  2023/3-7    a range of days (parts can be selected too, e.g. 2023/3-7/2)
+
+Options:
+  --input <path>  read the puzzle input from <path> instead of
+                  data/YYYY/DD/input.txt, or from stdin if <path> is `-`.
+                  Only valid when a single day is selected.
+  --param <name>=<value>
+                  override a tunable constant of the solution, e.g.
+                  `--param steps=6` for 2023/21. Can be repeated. Only
+                  valid when a single day is selected.
+  --bench <n>     run every selected day <n> times and report the minimum,
+                  median and standard deviation of each phase's time.
+  --json          print the answers, their details and timings as a single
+                  JSON document.";
+
+pub fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() || args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let mut input: Option<Input> = Option::None;
+    let mut overrides: Vec<(String, String)> = Vec::new();
+    let mut num_samples: usize = 1;
+    let mut json = false;
+    let mut selections = Vec::with_capacity(args.len());
+    let mut arg_iterator = args.iter();
+    while let Option::Some(arg) = arg_iterator.next() {
+        if arg == "--input" {
+            match arg_iterator.next() {
+                Option::Some(path) => input = Option::Some(Input::from_arg(path)),
+                Option::None => {
+                    eprintln!("error: missing path after --input\n\n{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            }
+            continue;
+        }
+        if arg == "--param" {
+            match arg_iterator.next().and_then(|param| param.split_once('=')) {
+                Option::Some((name, value)) => overrides.push((name.to_owned(), value.to_owned())),
+                Option::None => {
+                    eprintln!("error: expected <name>=<value> after --param\n\n{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            }
+            continue;
+        }
+        if arg == "--bench" {
+            match arg_iterator
+                .next()
+                .and_then(|n| n.parse::<usize>().ok())
+                .filter(|n| *n > 0)
+            {
+                Option::Some(n) => num_samples = n,
+                Option::None => {
+                    eprintln!(
+                        "error: expected a positive count after --bench\n\n{}",
+                        USAGE
+                    );
+                    return ExitCode::FAILURE;
+                }
+            }
+            continue;
+        }
+        if arg == "--json" {
+            json = true;
+            continue;
+        }
+        match Selection::from_arg(arg) {
+            Result::Ok(selection) => selections.push(selection),
+            Result::Err(message) => {
+                eprintln!("error: {}\n\n{}", message, USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let entries = get_entries();
+    let mut runs: Vec<(&Entry, Option<Part>)> = Vec::new();
+    for selection in selections.iter() {
+        let selected = entries
+            .iter()
+            .filter(|entry| selection.matches(entry))
+            .collect::<Vec<_>>();
+        if selected.is_empty() {
+            eprintln!("error: no solutions registered for {}", selection.label);
+            return ExitCode::FAILURE;
+        }
+        for entry in selected {
+            runs.push((entry, selection.part));
+        }
+    }
+
+    if runs.is_empty() {
+        eprintln!("error: no selection given\n\n{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+    if input.is_some() && runs.len() > 1 {
+        eprintln!("error: --input can only be used with a single day");
+        return ExitCode::FAILURE;
+    }
+    if !overrides.is_empty() && runs.len() > 1 {
+        eprintln!("error: --param can only be used with a single day");
+        return ExitCode::FAILURE;
+    }
+
+    // Stdin can only be read once, so keep it around when benchmarking.
+    if num_samples > 1 && matches!(input, Option::Some(Input::Stdin)) {
+        match io::read_to_string(io::stdin()) {
+            Result::Ok(text) => input = Option::Some(Input::Text(text)),
+            Result::Err(error) => {
+                eprintln!("error: cannot read <stdin>: {}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let mut reports = Vec::with_capacity(runs.len());
+    for (entry, part) in runs {
+        let entry_input = match input {
+            Option::Some(ref input) => input.clone(),
+            Option::None => entry.default_input(),
+        };
+        match run_entry(entry, &entry_input, &overrides, part, num_samples) {
+            Result::Ok(report) => {
+                if !json {
+                    report.print(num_samples);
+                }
+                reports.push(report);
+            }
+            Result::Err(error) => {
+                eprintln!("error: {}/{:02}: {}", entry.year, entry.day, error);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if json {
+        let results = reports.iter().map(Report::to_json).collect::<Vec<_>>();
+        println!(
+            "{{\"samples\":{},\"results\":[{}]}}",
+            num_samples,
+            results.join(",")
+        );
+    }
+
+    return ExitCode::SUCCESS;
+}
+
+fn run_entry(
+    entry: &Entry,
+    input: &Input,
+    overrides: &[(String, String)],
+    part: Option<Part>,
+    num_samples: usize,
+) -> Result<Report, SolveError> {
+    let parts = match part {
+        Option::Some(part) => vec![part],
+        Option::None => vec![Part::One, Part::Two],
+    };
+
+    let mut runs: Vec<Run> = Vec::with_capacity(num_samples);
+    for _ in 0..num_samples {
+        runs.push((entry.solve)(input, overrides, &parts)?);
+    }
+
+    let parse_samples = runs.iter().map(|run| run.parse_time).collect::<Vec<_>>();
+    let part_stats = (0..parts.len())
+        .map(|i| {
+            let samples = runs.iter().map(|run| run.part_times[i]).collect::<Vec<_>>();
+            return Stats::from_samples(&samples);
+        })
+        .collect();
+
+    let run = runs.swap_remove(0);
+    return Result::Ok(Report {
+        year: entry.year,
+        day: entry.day,
+        parts,
+        answers: run.answers,
+        details: run.details,
+        parse_stats: Stats::from_samples(&parse_samples),
+        part_stats,
+    });
+}
+
+/// Answers of a day, with timings aggregated over every sample.
+struct Report {
+    year: u16,
+    day: u8,
+    parts: Vec<Part>,
+    answers: Vec<Answer>,
+    details: Vec<Vec<(String, String)>>,
+    parse_stats: Stats,
+    part_stats: Vec<Stats>,
+}
+
+impl Report {
+    pub fn print(&self, num_samples: usize) {
+        println!(
+            "{}/{:02}  parse: {}",
+            self.year,
+            self.day,
+            self.parse_stats.describe(num_samples)
+        );
+        for i in 0..self.parts.len() {
+            println!(
+                "  part {}: {}  ({})",
+                self.parts[i],
+                self.answers[i],
+                self.part_stats[i].describe(num_samples)
+            );
+            for (name, value) in self.details[i].iter() {
+                println!("    {}: {}", name, value);
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let parts = (0..self.parts.len())
+            .map(|i| {
+                let details = self.details[i]
+                    .iter()
+                    .map(|(name, value)| format!("{}:{}", to_json(name), to_json(value)))
+                    .collect::<Vec<_>>();
+                return format!(
+                    "{{\"part\":{},\"answer\":{},\"details\":{{{}}},\"time\":{}}}",
+                    self.parts[i],
+                    answer_to_json(&self.answers[i]),
+                    details.join(","),
+                    self.part_stats[i].to_json()
+                );
+            })
+            .collect::<Vec<_>>();
+        return format!(
+            "{{\"year\":{},\"day\":{},\"parse\":{},\"parts\":[{}]}}",
+            self.year,
+            self.day,
+            self.parse_stats.to_json(),
+            parts.join(",")
+        );
+    }
+}
+
+fn answer_to_json(answer: &Answer) -> String {
+    return match answer {
+        Answer::Integer(value) => value.to_string(),
+        Answer::Text(value) => to_json(value),
+        Answer::Empty | Answer::Overflow => "null".to_owned(),
+    };
+}
+
+/// JSON string literal.
+fn to_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    return escaped;
+}
+
+struct Selection {
+    label: String,
+    year: u16,
+    days: Option<(u8, u8)>,
+    part: Option<Part>,
+}
+
+impl Selection {
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        let mut components = arg.split('/');
+        let year = components
+            .next()
+            .and_then(|s| s.parse::<u16>().ok())
+            .ok_or_else(|| format!("invalid year in selection `{}`", arg))?;
+
+        let days = match components.next() {
+            Option::Some(component) => Option::Some(Self::parse_days(arg, component)?),
+            Option::None => Option::None,
+        };
+
+        let part = match components.next() {
+            Option::Some(component) => match component {
+                "1" => Option::Some(Part::One),
+                "2" => Option::Some(Part::Two),
+                _ => return Result::Err(format!("invalid part in selection `{}`", arg)),
+            },
+            Option::None => Option::None,
+        };
+
+        if components.next().is_some() {
+            return Result::Err(format!("too many components in selection `{}`", arg));
+        }
+
+        return Result::Ok(Self {
+            label: arg.to_owned(),
+            year,
+            days,
+            part,
+        });
+    }
+
+    fn parse_days(arg: &str, component: &str) -> Result<(u8, u8), String> {
+        let parse_day = |s: &str| {
+            return s
+                .parse::<u8>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("invalid day in selection `{}`", arg));
+        };
+        return match component.split_once('-') {
+            Option::Some((start, end)) => {
+                let range = (parse_day(start)?, parse_day(end)?);
+                if range.0 > range.1 {
+                    return Result::Err(format!("empty day range in selection `{}`", arg));
+                }
+                Result::Ok(range)
+            }
+            Option::None => {
+                let day = parse_day(component)?;
+                Result::Ok((day, day))
+            }
+        };
+    }
+
+    pub fn matches(&self, entry: &Entry) -> bool {
+        if entry.year != self.year {
+            return false;
+        }
+        return match self.days {
+            Option::Some((start, end)) => start <= entry.day && entry.day <= end,
+            Option::None => true,
+        };
+    }
+}
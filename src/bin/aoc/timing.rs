@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+/// Summary of the wall-clock times of repeated runs of the same phase.
+#[derive(Clone, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty());
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 0 {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let count = samples.len() as f64;
+        let mean = samples.iter().map(|d| d.as_secs_f64()).sum::<f64>() / count;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        return Self {
+            min: sorted[0],
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        };
+    }
+
+    /// Human readable form. A single sample is shown as a plain duration.
+    pub fn describe(&self, num_samples: usize) -> String {
+        if num_samples == 1 {
+            return format!("{:.2?}", self.min);
+        }
+        return format!(
+            "min {:.2?}, median {:.2?}, stddev {:.2?}",
+            self.min, self.median, self.stddev
+        );
+    }
+
+    pub fn to_json(&self) -> String {
+        return format!(
+            "{{\"min_ns\":{},\"median_ns\":{},\"stddev_ns\":{}}}",
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.stddev.as_nanos()
+        );
+    }
+}
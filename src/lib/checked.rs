@@ -0,0 +1,174 @@
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub};
+use std::{error, fmt};
+
+use num::{CheckedAdd, CheckedMul, CheckedSub, Integer};
+
+/// An integer operation did not fit in its type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "integer overflow");
+    }
+}
+
+impl error::Error for Overflow {}
+
+/// Integer whose arithmetic never wraps. Once an operation overflows the value
+/// is lost, as is the result of every operation that uses it, so accumulating
+/// an answer in a `Checked` either gives the exact result or `Overflow`, in
+/// release builds too.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Checked<T> {
+    value: Option<T>,
+}
+
+impl<T> Checked<T> {
+    pub fn new(value: T) -> Self {
+        return Self {
+            value: Option::Some(value),
+        };
+    }
+
+    pub fn overflowed() -> Self {
+        return Self {
+            value: Option::None,
+        };
+    }
+
+    pub fn get(self) -> Result<T, Overflow> {
+        return self.value.ok_or(Overflow);
+    }
+
+    fn apply<F>(self, other: Self, f: F) -> Self
+    where
+        F: FnOnce(T, T) -> Option<T>,
+    {
+        let value = match (self.value, other.value) {
+            (Option::Some(a), Option::Some(b)) => f(a, b),
+            _ => Option::None,
+        };
+        return Self { value };
+    }
+}
+
+impl<T: Integer + CheckedMul + Copy> Checked<T> {
+    pub fn lcm(self, other: Self) -> Self {
+        return self.apply(other, |a, b| {
+            if a.is_zero() || b.is_zero() {
+                return Option::Some(T::zero());
+            }
+            return (a / a.gcd(&b)).checked_mul(&b);
+        });
+    }
+}
+
+impl<T: Default> Default for Checked<T> {
+    fn default() -> Self {
+        return Self::new(T::default());
+    }
+}
+
+impl<T> From<T> for Checked<T> {
+    fn from(value: T) -> Self {
+        return Self::new(value);
+    }
+}
+
+impl<T: CheckedAdd> Add for Checked<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        return self.apply(other, |a, b| a.checked_add(&b));
+    }
+}
+
+impl<T: CheckedAdd> Add<T> for Checked<T> {
+    type Output = Self;
+
+    fn add(self, other: T) -> Self {
+        return self + Self::new(other);
+    }
+}
+
+impl<T: CheckedAdd + Copy> AddAssign for Checked<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: CheckedAdd + Copy> AddAssign<T> for Checked<T> {
+    fn add_assign(&mut self, other: T) {
+        *self = *self + other;
+    }
+}
+
+impl<T: CheckedSub> Sub for Checked<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        return self.apply(other, |a, b| a.checked_sub(&b));
+    }
+}
+
+impl<T: CheckedSub> Sub<T> for Checked<T> {
+    type Output = Self;
+
+    fn sub(self, other: T) -> Self {
+        return self - Self::new(other);
+    }
+}
+
+impl<T: CheckedMul> Mul for Checked<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        return self.apply(other, |a, b| a.checked_mul(&b));
+    }
+}
+
+impl<T: CheckedMul> Mul<T> for Checked<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        return self * Self::new(other);
+    }
+}
+
+impl<T: CheckedMul + Copy> MulAssign for Checked<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T: CheckedMul + Copy> MulAssign<T> for Checked<T> {
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Integer + CheckedAdd> Sum for Checked<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        return iter.fold(Self::new(T::zero()), |acc, value| acc + value);
+    }
+}
+
+impl<T: Integer + CheckedAdd> Sum<T> for Checked<T> {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        return iter.fold(Self::new(T::zero()), |acc, value| acc + value);
+    }
+}
+
+impl<T: Integer + CheckedMul> Product for Checked<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        return iter.fold(Self::new(T::one()), |acc, value| acc * value);
+    }
+}
+
+impl<T: Integer + CheckedMul> Product<T> for Checked<T> {
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        return iter.fold(Self::new(T::one()), |acc, value| acc * value);
+    }
+}
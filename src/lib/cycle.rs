@@ -0,0 +1,139 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Shape of the sequence `x0, f(x0), f(f(x0)), ...` of an iterated function
+/// over a finite set of states: after `prefix` steps it repeats every `period`
+/// steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Earliest step with the same state as `step`.
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.prefix {
+            return step;
+        }
+        return self.prefix + (step - self.prefix) % self.period;
+    }
+
+    /// State after `step` iterations of `f` from `start`, only iterating up
+    /// to the reduced step.
+    pub fn state_at<S, F>(&self, start: S, mut f: F, step: usize) -> S
+    where
+        F: FnMut(&S) -> S,
+    {
+        let mut state = start;
+        for _ in 0..self.reduce(step) {
+            state = f(&state);
+        }
+        return state;
+    }
+}
+
+/// Floyd's tortoise and hare. Only keeps two states in memory, at the cost
+/// of evaluating `f` about three times per step of the sequence.
+/// Source: https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare
+pub fn floyd<S, F>(start: &S, mut f: F) -> Cycle
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = f(start);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        let hare_step = f(&hare);
+        hare = f(&hare_step);
+    }
+
+    // The distance between both is now a multiple of the period, so they
+    // meet again where the cycle starts.
+    let mut prefix = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        period += 1;
+    }
+    return Cycle { prefix, period };
+}
+
+/// Brent's algorithm. Like Floyd's it only keeps two states in memory, but
+/// finds the period first and evaluates `f` fewer times.
+/// Source: https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+pub fn brent<S, F>(start: &S, mut f: F) -> Cycle
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = f(start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = f(&hare);
+        period += 1;
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start.clone();
+    for _ in 0..period {
+        hare = f(&hare);
+    }
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+    return Cycle { prefix, period };
+}
+
+/// Records every state until one repeats, evaluating `f` once per step.
+/// States are looked up by hash, and a match is only accepted once the states
+/// compare equal, so hash collisions cannot produce a wrong cycle. Returns
+/// the cycle together with the states of steps `0..prefix + period`, so the
+/// state at any step is `states[cycle.reduce(step)]`.
+pub fn find_cycle<S, F>(start: S, mut f: F) -> (Cycle, Vec<S>)
+where
+    S: Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut states: Vec<S> = vec![];
+    let mut steps_by_hash: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut state = start;
+    loop {
+        let hash = get_hash(&state);
+        let candidates = steps_by_hash.entry(hash).or_default();
+        if let Option::Some(&prefix) = candidates.iter().find(|&&step| states[step] == state) {
+            let period = states.len() - prefix;
+            return (Cycle { prefix, period }, states);
+        }
+        candidates.push(states.len());
+        let next = f(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+fn get_hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    return hasher.finish();
+}
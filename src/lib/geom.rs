@@ -0,0 +1,364 @@
+use num::{Signed, Zero};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        return Self { x, y };
+    }
+}
+
+impl<T: Signed + Copy> Point2<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        return (self.x - other.x).abs() + (self.y - other.y).abs();
+    }
+
+    /// Point reached after `steps` unit moves in the given direction.
+    pub fn moved(&self, direction: Direction4, steps: T) -> Self {
+        return *self + direction.offset() * steps;
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        return self.x * other.x + self.y * other.y;
+    }
+
+    /// Z component of the cross product, which is zero for parallel vectors
+    /// and positive when `other` is clockwise from `self` on screen, where `y`
+    /// grows downwards.
+    pub fn cross(&self, other: &Self) -> T {
+        return self.x * other.y - self.y * other.x;
+    }
+}
+
+impl Point2<usize> {
+    /// Unit move in the given direction, or `None` when it would leave the
+    /// first quadrant.
+    pub fn step(&self, direction: Direction4) -> Option<Self> {
+        let offset: Point2<i64> = direction.offset();
+        let x = self.x.checked_add_signed(offset.x as isize)?;
+        let y = self.y.checked_add_signed(offset.y as isize)?;
+        return Option::Some(Self { x, y });
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        return Self { x, y };
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        return (point.x, point.y);
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        return Self::new(self.x + other.x, self.y + other.y);
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        return Self::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        return Self::new(self.x * factor, self.y * factor);
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        return Self::new(-self.x, -self.y);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        return Self { x, y, z };
+    }
+}
+
+impl<T: Signed + Copy> Point3<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        return (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs();
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        return Self { x, y, z };
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        return (point.x, point.y, point.z);
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        return Self::new(self.x + other.x, self.y + other.y, self.z + other.z);
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        return Self::new(self.x - other.x, self.y - other.y, self.z - other.z);
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        return Self::new(self.x * factor, self.y * factor, self.z * factor);
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        return Self::new(-self.x, -self.y, -self.z);
+    }
+}
+
+/// Orthogonal directions in screen coordinates: north is towards smaller `y`.
+/// Listed counterclockwise, so turning left moves forward in `ALL`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    North,
+    West,
+    South,
+    East,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::West,
+        Direction4::South,
+        Direction4::East,
+    ];
+
+    /// Position in `ALL`, for use as an array index.
+    pub fn index(&self) -> usize {
+        return *self as usize;
+    }
+
+    pub fn turn_left(&self) -> Self {
+        return Self::ALL[(self.index() + 1) % 4];
+    }
+
+    pub fn turn_right(&self) -> Self {
+        return Self::ALL[(self.index() + 3) % 4];
+    }
+
+    pub fn reverse(&self) -> Self {
+        return Self::ALL[(self.index() + 2) % 4];
+    }
+
+    pub fn offset<T: Signed>(&self) -> Point2<T> {
+        return match self {
+            Direction4::North => Point2::new(T::zero(), -T::one()),
+            Direction4::West => Point2::new(-T::one(), T::zero()),
+            Direction4::South => Point2::new(T::zero(), T::one()),
+            Direction4::East => Point2::new(T::one(), T::zero()),
+        };
+    }
+
+    pub fn bit(&self) -> u8 {
+        return 1 << self.index();
+    }
+}
+
+/// Set of `Direction4` values packed in the low four bits of a byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Directions4(u8);
+
+impl Directions4 {
+    pub fn empty() -> Self {
+        return Self(0);
+    }
+
+    pub fn all() -> Self {
+        return Self(0b1111);
+    }
+
+    pub fn from_bits(bits: u8) -> Self {
+        return Self(bits & 0b1111);
+    }
+
+    pub fn bits(&self) -> u8 {
+        return self.0;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.0.is_zero();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.0.count_ones() as usize;
+    }
+
+    pub fn contains(&self, direction: Direction4) -> bool {
+        return self.0 & direction.bit() != 0;
+    }
+
+    /// Adds the direction, returning whether it was not already present.
+    pub fn insert(&mut self, direction: Direction4) -> bool {
+        let is_new = !self.contains(direction);
+        self.0 |= direction.bit();
+        return is_new;
+    }
+
+    pub fn remove(&mut self, direction: Direction4) -> bool {
+        let was_present = self.contains(direction);
+        self.0 &= !direction.bit();
+        return was_present;
+    }
+
+    pub fn without(&self, direction: Direction4) -> Self {
+        return Self(self.0 & !direction.bit());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Direction4> {
+        let set = *self;
+        return Direction4::ALL
+            .into_iter()
+            .filter(move |&d| set.contains(d));
+    }
+}
+
+impl FromIterator<Direction4> for Directions4 {
+    fn from_iter<I: IntoIterator<Item = Direction4>>(iter: I) -> Self {
+        let mut set = Self::empty();
+        for direction in iter {
+            set.insert(direction);
+        }
+        return set;
+    }
+}
+
+/// Orthogonal and diagonal directions, listed counterclockwise from north
+/// like `Direction4`, so a `Direction4` at index `i` is at index `2 * i` here.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthWest,
+    West,
+    SouthWest,
+    South,
+    SouthEast,
+    East,
+    NorthEast,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthWest,
+        Direction8::West,
+        Direction8::SouthWest,
+        Direction8::South,
+        Direction8::SouthEast,
+        Direction8::East,
+        Direction8::NorthEast,
+    ];
+
+    /// Position in `ALL`, for use as an array index.
+    pub fn index(&self) -> usize {
+        return *self as usize;
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    pub fn turn_left(&self) -> Self {
+        return Self::ALL[(self.index() + 1) % 8];
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        return Self::ALL[(self.index() + 7) % 8];
+    }
+
+    pub fn reverse(&self) -> Self {
+        return Self::ALL[(self.index() + 4) % 8];
+    }
+
+    pub fn offset<T: Signed>(&self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthWest => (-1, -1),
+            Direction8::West => (-1, 0),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthEast => (1, 1),
+            Direction8::East => (1, 0),
+            Direction8::NorthEast => (1, -1),
+        };
+        return Point2::new(Self::unit(x), Self::unit(y));
+    }
+
+    fn unit<T: Signed>(value: i8) -> T {
+        return match value {
+            -1 => -T::one(),
+            1 => T::one(),
+            _ => T::zero(),
+        };
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        return match direction {
+            Direction4::North => Direction8::North,
+            Direction4::West => Direction8::West,
+            Direction4::South => Direction8::South,
+            Direction4::East => Direction8::East,
+        };
+    }
+}
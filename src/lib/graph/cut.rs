@@ -0,0 +1,265 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use rand::Rng;
+
+use super::{EdgeWeight, Graph};
+
+/// A cut of an undirected graph into two non-empty sides.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinCut {
+    /// Total weight of the edges crossing the cut.
+    pub weight: u64,
+    /// Crossing edges, each given from its endpoint in the first partition.
+    pub edges: Vec<(usize, usize)>,
+    /// Node indices on each side of the cut, in index order.
+    pub partitions: [Vec<usize>; 2],
+}
+
+impl MinCut {
+    /// Cut between the unmarked nodes, which form the first partition, and
+    /// the nodes marked in `side`.
+    pub fn from_side<L, W>(graph: &Graph<L, W>, side: &Vec<bool>) -> Self
+    where
+        L: Clone + Eq + Hash,
+        W: Clone + EdgeWeight,
+    {
+        let mut weight = 0;
+        let mut edges = vec![];
+        for from in graph.indices() {
+            for (to, edge_weight) in graph.edges(from) {
+                if from < *to && side[from] != side[*to] {
+                    weight += edge_weight.weight();
+                    edges.push(if side[from] { (*to, from) } else { (from, *to) });
+                }
+            }
+        }
+        edges.sort();
+
+        let partitions = [
+            graph.indices().filter(|&i| !side[i]).collect(),
+            graph.indices().filter(|&i| side[i]).collect(),
+        ];
+        return Self {
+            weight,
+            edges,
+            partitions,
+        };
+    }
+}
+
+/// Deterministic global minimum cut by Stoer–Wagner. Each phase grows a set
+/// from an arbitrary node, always adding the most tightly connected node; the
+/// last two nodes added are separated by a minimum cut of weight equal to the
+/// connection of the last one, after which they are merged. The lightest of
+/// those phase cuts is a global minimum cut.
+/// Source: https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm
+///
+/// Returns `None` for graphs with fewer than two nodes.
+pub fn stoer_wagner<L, W>(graph: &Graph<L, W>) -> Option<MinCut>
+where
+    L: Clone + Eq + Hash,
+    W: Clone + EdgeWeight,
+{
+    assert!(
+        !graph.is_directed(),
+        "minimum cuts need an undirected graph"
+    );
+    let num_nodes = graph.len();
+    if num_nodes < 2 {
+        return Option::None;
+    }
+
+    // Merged nodes keep the summed weights to every other merged node.
+    let mut adjacency: Vec<HashMap<usize, u64>> = vec![HashMap::new(); num_nodes];
+    for from in graph.indices() {
+        for (to, weight) in graph.edges(from) {
+            if from != *to {
+                *adjacency[from].entry(*to).or_insert(0) += weight.weight();
+            }
+        }
+    }
+    let mut members: Vec<Vec<usize>> = graph.indices().map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = graph.indices().collect();
+
+    let mut best: Option<(u64, Vec<usize>)> = Option::None;
+    let mut connection = vec![0; num_nodes];
+    let mut added = vec![false; num_nodes];
+    while active.len() > 1 {
+        for &node in active.iter() {
+            connection[node] = 0;
+            added[node] = false;
+        }
+        /* Stale entries are skipped, ties go to the lowest index */
+        let mut queue: BinaryHeap<(u64, Reverse<usize>)> =
+            active.iter().map(|&node| (0, Reverse(node))).collect();
+        let mut previous = usize::MAX;
+        let mut last = usize::MAX;
+        while let Option::Some((weight, Reverse(node))) = queue.pop() {
+            if added[node] || weight != connection[node] {
+                continue;
+            }
+            added[node] = true;
+            previous = last;
+            last = node;
+            for (&next, &edge_weight) in adjacency[node].iter() {
+                if !added[next] {
+                    connection[next] += edge_weight;
+                    queue.push((connection[next], Reverse(next)));
+                }
+            }
+        }
+
+        let phase_weight = connection[last];
+        if best
+            .as_ref()
+            .map_or(true, |(weight, _)| phase_weight < *weight)
+        {
+            best = Option::Some((phase_weight, members[last].clone()));
+        }
+
+        // Merge the last node into the one added before it.
+        let last_edges = std::mem::take(&mut adjacency[last]);
+        for (next, weight) in last_edges {
+            adjacency[next].remove(&last);
+            if next != previous {
+                *adjacency[previous].entry(next).or_insert(0) += weight;
+                *adjacency[next].entry(previous).or_insert(0) += weight;
+            }
+        }
+        let last_members = std::mem::take(&mut members[last]);
+        members[previous].extend(last_members);
+        active.retain(|&node| node != last);
+    }
+
+    let (_, best_side) = best.unwrap();
+    let mut side = vec![false; num_nodes];
+    for node in best_side {
+        side[node] = true;
+    }
+    return Option::Some(MinCut::from_side(graph, &side));
+}
+
+/// Randomized minimum cut by Karger's algorithm, trying up to `iterations`
+/// contractions and keeping the lightest cut found. Stops early once a cut
+/// weighing at most `target` is found, so pass 0 to always run every
+/// iteration.
+///
+/// We contract all edges between two nodes. It is more likely to pick an edge
+/// that is not part of the min-cut than one that is part of the min-cut.
+/// Even as we contract the graph, nodes separated by the min-cut will only
+/// be connected once, while nodes on the same side of a min-cut may be connected
+/// to each other by many edges, product of the contraction process. This keeps the
+/// likelihood in favor of contracting edges that are not part of the min-cut.
+/// Edges are picked with probability proportional to their weight, which is
+/// the same as contracting them in increasing order of exponentially
+/// distributed keys whose rates are the weights.
+/// Source: https://en.wikipedia.org/wiki/Karger's_algorithm
+///
+/// Returns `None` for graphs with fewer than two nodes or if `iterations` is
+/// zero.
+pub fn karger<L, W, R>(
+    graph: &Graph<L, W>,
+    rng: &mut R,
+    iterations: usize,
+    target: u64,
+) -> Option<MinCut>
+where
+    L: Clone + Eq + Hash,
+    W: Clone + EdgeWeight,
+    R: Rng,
+{
+    assert!(
+        !graph.is_directed(),
+        "minimum cuts need an undirected graph"
+    );
+    let num_nodes = graph.len();
+    if num_nodes < 2 {
+        return Option::None;
+    }
+
+    let mut edges = vec![];
+    for from in graph.indices() {
+        for (to, weight) in graph.edges(from) {
+            if from < *to {
+                edges.push((f64::INFINITY, from, *to, weight.weight()));
+            }
+        }
+    }
+
+    let mut best: Option<MinCut> = Option::None;
+    for _ in 0..iterations {
+        for (key, _, _, weight) in edges.iter_mut() {
+            // Edges that weigh nothing are only picked after all the others.
+            let uniform: f64 = rng.gen();
+            *key = match *weight {
+                0 => f64::INFINITY,
+                weight => -(1.0 - uniform).ln() / weight as f64,
+            };
+        }
+        edges.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut groups = DisjointSets::new(num_nodes);
+        let mut num_groups = num_nodes;
+        for &(_, from, to, _) in edges.iter() {
+            if num_groups == 2 {
+                break;
+            }
+            if groups.union(from, to) {
+                num_groups -= 1;
+            }
+        }
+
+        // A disconnected graph leaves more groups: the first one stays apart
+        // from the rest.
+        let root = groups.find(0);
+        let side = (0..num_nodes)
+            .map(|node| groups.find(node) != root)
+            .collect();
+        let cut = MinCut::from_side(graph, &side);
+        if best.as_ref().map_or(true, |best| cut.weight < best.weight) {
+            best = Option::Some(cut);
+        }
+        if best.as_ref().unwrap().weight <= target {
+            break;
+        }
+    }
+    return best;
+}
+
+struct DisjointSets {
+    parents: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        return Self {
+            parents: (0..len).collect(),
+        };
+    }
+
+    fn find(&mut self, node: usize) -> usize {
+        let mut root = node;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut curr = node;
+        while self.parents[curr] != root {
+            let next = self.parents[curr];
+            self.parents[curr] = root;
+            curr = next;
+        }
+        return root;
+    }
+
+    /// Merges the sets of both nodes, returning whether they were apart.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let a = self.find(a);
+        let b = self.find(b);
+        if a == b {
+            return false;
+        }
+        self.parents[b] = a;
+        return true;
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use super::{EdgeWeight, Graph};
+
+/// Residual graph of a flow network. Every edge of the original graph is
+/// stored as a pair of arcs, so the reverse of arc `i` is arc `i ^ 1`.
+/// Undirected edges have their capacity in both directions.
+#[derive(Clone, Debug)]
+pub struct ResidualGraph {
+    adjacency: Vec<Vec<usize>>,
+    tails: Vec<usize>,
+    heads: Vec<usize>,
+    capacities: Vec<u64>,
+    residuals: Vec<u64>,
+}
+
+impl ResidualGraph {
+    fn new<L, W>(graph: &Graph<L, W>) -> Self
+    where
+        L: Clone + Eq + Hash,
+        W: Clone + EdgeWeight,
+    {
+        let mut residual = Self {
+            adjacency: vec![vec![]; graph.len()],
+            tails: vec![],
+            heads: vec![],
+            capacities: vec![],
+            residuals: vec![],
+        };
+        for from in graph.indices() {
+            for (to, weight) in graph.edges(from) {
+                if from == *to || (!graph.is_directed() && from > *to) {
+                    continue;
+                }
+                let capacity = weight.weight();
+                let reverse_capacity = if graph.is_directed() { 0 } else { capacity };
+                residual.add_arc(from, *to, capacity);
+                residual.add_arc(*to, from, reverse_capacity);
+            }
+        }
+        return residual;
+    }
+
+    fn add_arc(&mut self, from: usize, to: usize, capacity: u64) {
+        self.adjacency[from].push(self.heads.len());
+        self.tails.push(from);
+        self.heads.push(to);
+        self.capacities.push(capacity);
+        self.residuals.push(capacity);
+    }
+
+    fn push(&mut self, arc: usize, amount: u64) {
+        self.residuals[arc] -= amount;
+        self.residuals[arc ^ 1] += amount;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.adjacency.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.adjacency.is_empty();
+    }
+
+    /// Arcs leaving the node that can still carry flow, with their remaining
+    /// capacity.
+    pub fn edges(&self, index: usize) -> impl Iterator<Item = (usize, u64)> + '_ {
+        return self.adjacency[index]
+            .iter()
+            .filter(|&&arc| self.residuals[arc] > 0)
+            .map(|&arc| (self.heads[arc], self.residuals[arc]));
+    }
+
+    /// Nodes reachable from `source` through arcs that can still carry flow.
+    pub fn reachable(&self, source: usize) -> Vec<bool> {
+        let mut reached = vec![false; self.len()];
+        let mut queue = VecDeque::from([source]);
+        reached[source] = true;
+        while let Option::Some(node) = queue.pop_front() {
+            for (next, _) in self.edges(node) {
+                if !reached[next] {
+                    reached[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        return reached;
+    }
+
+    /// Levels of a breadth first search from `source` through arcs that can
+    /// still carry flow, or `None` for unreached nodes.
+    fn levels(&self, source: usize) -> Vec<Option<usize>> {
+        let mut levels = vec![Option::None; self.len()];
+        let mut queue = VecDeque::from([source]);
+        levels[source] = Option::Some(0);
+        while let Option::Some(node) = queue.pop_front() {
+            let level = levels[node].unwrap();
+            for (next, _) in self.edges(node) {
+                if levels[next].is_none() {
+                    levels[next] = Option::Some(level + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        return levels;
+    }
+}
+
+/// Result of a maximum flow computation.
+#[derive(Clone, Debug)]
+pub struct MaxFlow {
+    pub value: u64,
+    pub residual: ResidualGraph,
+    /// Saturated edges from the nodes reachable from the source in the
+    /// residual graph to the rest, which together form a minimum cut.
+    pub cut_edges: Vec<(usize, usize)>,
+}
+
+impl MaxFlow {
+    fn new(value: u64, residual: ResidualGraph, source: usize) -> Self {
+        let reachable = residual.reachable(source);
+        let mut cut_edges = vec![];
+        for arc in 0..residual.heads.len() {
+            let (tail, head) = (residual.tails[arc], residual.heads[arc]);
+            if residual.capacities[arc] > 0 && reachable[tail] && !reachable[head] {
+                cut_edges.push((tail, head));
+            }
+        }
+        cut_edges.sort();
+        return Self {
+            value,
+            residual,
+            cut_edges,
+        };
+    }
+}
+
+/// Maximum flow from `source` to `sink` by Edmonds–Karp, which augments along
+/// shortest paths. Edge weights are used as capacities.
+/// Source: https://en.wikipedia.org/wiki/Edmonds%E2%80%93Karp_algorithm
+pub fn edmonds_karp<L, W>(graph: &Graph<L, W>, source: usize, sink: usize) -> MaxFlow
+where
+    L: Clone + Eq + Hash,
+    W: Clone + EdgeWeight,
+{
+    assert!(source != sink, "the source cannot be the sink");
+    let mut residual = ResidualGraph::new(graph);
+    let mut value = 0;
+    loop {
+        // Arc used to reach each node
+        let mut parents: Vec<Option<usize>> = vec![Option::None; residual.len()];
+        let mut queue = VecDeque::from([source]);
+        while let Option::Some(node) = queue.pop_front() {
+            if node == sink {
+                break;
+            }
+            for &arc in residual.adjacency[node].iter() {
+                let next = residual.heads[arc];
+                if residual.residuals[arc] > 0 && next != source && parents[next].is_none() {
+                    parents[next] = Option::Some(arc);
+                    queue.push_back(next);
+                }
+            }
+        }
+        if parents[sink].is_none() {
+            break;
+        }
+
+        let mut path = vec![];
+        let mut node = sink;
+        while let Option::Some(arc) = parents[node] {
+            path.push(arc);
+            node = residual.tails[arc];
+        }
+        let amount = path
+            .iter()
+            .map(|&arc| residual.residuals[arc])
+            .min()
+            .unwrap();
+        for arc in path {
+            residual.push(arc, amount);
+        }
+        value += amount;
+    }
+    return MaxFlow::new(value, residual, source);
+}
+
+/// Maximum flow from `source` to `sink` by Dinic's algorithm, which saturates
+/// a blocking flow of the level graph in each phase. Edge weights are used as
+/// capacities.
+/// Source: https://en.wikipedia.org/wiki/Dinic%27s_algorithm
+pub fn dinic<L, W>(graph: &Graph<L, W>, source: usize, sink: usize) -> MaxFlow
+where
+    L: Clone + Eq + Hash,
+    W: Clone + EdgeWeight,
+{
+    assert!(source != sink, "the source cannot be the sink");
+    let mut residual = ResidualGraph::new(graph);
+    let mut value = 0;
+    loop {
+        let levels = residual.levels(source);
+        if levels[sink].is_none() {
+            break;
+        }
+        // Next arc to try from each node, so dead ends are only visited once
+        let mut next_arcs = vec![0; residual.len()];
+        loop {
+            let amount = push_blocking_flow(
+                &mut residual,
+                &levels,
+                &mut next_arcs,
+                source,
+                sink,
+                u64::MAX,
+            );
+            if amount == 0 {
+                break;
+            }
+            value += amount;
+        }
+    }
+    return MaxFlow::new(value, residual, source);
+}
+
+fn push_blocking_flow(
+    residual: &mut ResidualGraph,
+    levels: &Vec<Option<usize>>,
+    next_arcs: &mut Vec<usize>,
+    node: usize,
+    sink: usize,
+    limit: u64,
+) -> u64 {
+    if node == sink {
+        return limit;
+    }
+    while next_arcs[node] < residual.adjacency[node].len() {
+        let arc = residual.adjacency[node][next_arcs[node]];
+        let next = residual.heads[arc];
+        let remaining = residual.residuals[arc];
+        if remaining > 0 && levels[next] == levels[node].map(|level| level + 1) {
+            let amount = push_blocking_flow(
+                residual,
+                levels,
+                next_arcs,
+                next,
+                sink,
+                limit.min(remaining),
+            );
+            if amount > 0 {
+                residual.push(arc, amount);
+                return amount;
+            }
+        }
+        next_arcs[node] += 1;
+    }
+    return 0;
+}
@@ -0,0 +1,85 @@
+use super::Graph;
+use crate::geom::Direction4;
+use crate::grid::Grid;
+
+/// Contracts the corridors of a maze into a directed graph between its
+/// junctions, weighted by the number of steps along each corridor. Node labels
+/// are the grid positions of the junctions, so `Graph::label` and
+/// `Graph::index_of` map between nodes and the grid.
+///
+/// `can_enter` tells whether a cell can be entered moving in the given
+/// direction, which allows one-way cells. Cells that can be entered from some
+/// direction are open, and open cells with more than two open neighbours are
+/// junctions, as are the `terminals` (e.g. the start and the end of the maze).
+/// Corridors that end in a dead end or lead back to where they started are
+/// left out.
+pub fn junction_graph<T, F>(
+    grid: &Grid<T>,
+    terminals: &[(usize, usize)],
+    mut can_enter: F,
+) -> Graph<(usize, usize), usize>
+where
+    F: FnMut(&T, Direction4) -> bool,
+{
+    let open = grid.map(|value| {
+        Direction4::ALL
+            .into_iter()
+            .any(|direction| can_enter(value, direction))
+    });
+    let is_junction = |position: (usize, usize)| {
+        if !open[position] {
+            return false;
+        }
+        if terminals.contains(&position) {
+            return true;
+        }
+        return open
+            .neighbours4(position)
+            .filter(|&neighbour| open[neighbour])
+            .count()
+            > 2;
+    };
+
+    let mut graph = Graph::new_directed();
+    for position in grid.positions() {
+        if is_junction(position) {
+            graph.add_node(position);
+        }
+    }
+
+    let mut get_moves = |position: (usize, usize)| {
+        return Direction4::ALL
+            .into_iter()
+            .filter_map(|direction| {
+                let next = grid.step(position, direction)?;
+                return can_enter(&grid[next], direction).then_some(next);
+            })
+            .collect::<Vec<_>>();
+    };
+    for index in graph.indices() {
+        let start = *graph.label(index);
+        for mut curr in get_moves(start) {
+            let mut prev = start;
+            let mut length = 1;
+            let mut reached = true;
+            while !is_junction(curr) {
+                match get_moves(curr).into_iter().find(|&next| next != prev) {
+                    Option::Some(next) => {
+                        prev = curr;
+                        curr = next;
+                        length += 1;
+                    }
+                    Option::None => {
+                        reached = false;
+                        break;
+                    }
+                }
+            }
+            if reached && curr != start {
+                let next_index = graph.index_of(&curr).unwrap();
+                graph.add_edge(index, next_index, length);
+            }
+        }
+    }
+    return graph;
+}
@@ -0,0 +1,239 @@
+pub mod cut;
+pub mod flow;
+pub mod maze;
+pub mod path;
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+/// Table assigning consecutive indices to labels, in order of first use.
+#[derive(Clone, Debug)]
+pub struct Interner<L> {
+    labels: Vec<L>,
+    index_map: HashMap<L, usize>,
+}
+
+impl<L: Clone + Eq + Hash> Interner<L> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        return Self {
+            labels: Vec::new(),
+            index_map: HashMap::new(),
+        };
+    }
+
+    /// Index of the label, adding it to the table if it is new.
+    pub fn intern(&mut self, label: L) -> usize {
+        if let Option::Some(&index) = self.index_map.get(&label) {
+            return index;
+        }
+        let index = self.labels.len();
+        self.labels.push(label.clone());
+        self.index_map.insert(label, index);
+        return index;
+    }
+
+    pub fn get<Q>(&self, label: &Q) -> Option<usize>
+    where
+        L: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        return self.index_map.get(label).copied();
+    }
+
+    pub fn label(&self, index: usize) -> &L {
+        return &self.labels[index];
+    }
+
+    pub fn len(&self) -> usize {
+        return self.labels.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.labels.is_empty();
+    }
+}
+
+/// Adjacency list graph whose nodes are identified by interned labels. Edge
+/// weights are of type `W`, which is `()` for unweighted graphs. Undirected
+/// edges are stored once in each endpoint's list.
+#[derive(Clone, Debug)]
+pub struct Graph<L, W = ()> {
+    directed: bool,
+    nodes: Interner<L>,
+    adjacency: Vec<Vec<(usize, W)>>,
+}
+
+impl<L: Clone + Eq + Hash, W: Clone> Graph<L, W> {
+    pub fn new_directed() -> Self {
+        return Self::new(true);
+    }
+
+    pub fn new_undirected() -> Self {
+        return Self::new(false);
+    }
+
+    fn new(directed: bool) -> Self {
+        return Self {
+            directed,
+            nodes: Interner::new(),
+            adjacency: Vec::new(),
+        };
+    }
+
+    pub fn is_directed(&self) -> bool {
+        return self.directed;
+    }
+
+    /// Index of the node with this label, adding the node if it is new.
+    pub fn add_node(&mut self, label: L) -> usize {
+        let index = self.nodes.intern(label);
+        if index == self.adjacency.len() {
+            self.adjacency.push(Vec::new());
+        }
+        return index;
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, weight.clone()));
+        }
+        self.adjacency[from].push((to, weight));
+    }
+
+    /// Adds an edge between two labels, adding the nodes if they are new.
+    pub fn add_labelled_edge(&mut self, from: L, to: L, weight: W) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge(from, to, weight);
+    }
+
+    pub fn index_of<Q>(&self, label: &Q) -> Option<usize>
+    where
+        L: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        return self.nodes.get(label);
+    }
+
+    pub fn label(&self, index: usize) -> &L {
+        return self.nodes.label(index);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.adjacency.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.adjacency.is_empty();
+    }
+
+    pub fn indices(&self) -> Range<usize> {
+        return 0..self.adjacency.len();
+    }
+
+    /// Outgoing edges of the node, in insertion order.
+    pub fn edges(&self, index: usize) -> &[(usize, W)] {
+        return &self.adjacency[index];
+    }
+
+    pub fn neighbours(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        return self.adjacency[index].iter().map(|(next, _)| *next);
+    }
+
+    /// Nodes with an edge into the given node, in index order.
+    pub fn predecessors(&self, index: usize) -> Vec<usize> {
+        if !self.directed {
+            let mut predecessors = self.neighbours(index).collect::<Vec<_>>();
+            predecessors.sort();
+            return predecessors;
+        }
+        return self
+            .indices()
+            .filter(|&source| self.neighbours(source).any(|next| next == index))
+            .collect();
+    }
+
+    /// Number of edges, counting each undirected edge once.
+    pub fn edge_count(&self) -> usize {
+        let total = self
+            .adjacency
+            .iter()
+            .map(|edges| edges.len())
+            .sum::<usize>();
+        if self.directed {
+            return total;
+        }
+        let loops = self
+            .indices()
+            .map(|index| self.neighbours(index).filter(|&next| next == index).count())
+            .sum::<usize>();
+        return (total + loops) / 2;
+    }
+}
+
+impl Graph<String> {
+    /// Builds an unweighted graph from adjacency lines such as `a -> b, c` or
+    /// `a: b c`. Fails with the index of the first line that is not one.
+    pub fn from_adjacency_lines<I, S>(lines: I, directed: bool) -> Result<Self, usize>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut graph = Self::new(directed);
+        for (i, line) in lines.into_iter().enumerate() {
+            let (source, destinations) = parse_adjacency_line(line.as_ref()).ok_or(i)?;
+            let source = graph.add_node(source.to_owned());
+            for destination in destinations {
+                let destination = graph.add_node(destination.to_owned());
+                graph.add_edge(source, destination, ());
+            }
+        }
+        return Result::Ok(graph);
+    }
+}
+
+/// Weight of an edge as used by the cut algorithms. Unweighted edges weigh one.
+pub trait EdgeWeight {
+    fn weight(&self) -> u64;
+}
+
+impl EdgeWeight for () {
+    fn weight(&self) -> u64 {
+        return 1;
+    }
+}
+
+impl EdgeWeight for u32 {
+    fn weight(&self) -> u64 {
+        return *self as u64;
+    }
+}
+
+impl EdgeWeight for u64 {
+    fn weight(&self) -> u64 {
+        return *self;
+    }
+}
+
+impl EdgeWeight for usize {
+    fn weight(&self) -> u64 {
+        return *self as u64;
+    }
+}
+
+/// Splits an adjacency line such as `a -> b, c` or `a: b c` into the source
+/// label and its destination labels, or `None` if it is not one.
+pub fn parse_adjacency_line(line: &str) -> Option<(&str, Vec<&str>)> {
+    let (source, destinations) = line.split_once("->").or_else(|| line.split_once(':'))?;
+    if source.trim().is_empty() {
+        return Option::None;
+    }
+    let destinations = destinations
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|label| !label.is_empty())
+        .collect();
+    return Option::Some((source.trim(), destinations));
+}
@@ -0,0 +1,223 @@
+use std::hash::Hash;
+
+use super::{EdgeWeight, Graph};
+use crate::search::Path;
+
+/// Longest simple path from `source` to `target`, with edge weights as
+/// lengths. Returns `None` if the target cannot be reached.
+///
+/// If the graph has no directed cycles the longest path to the target from
+/// every node only depends on the node, so it is memoized. Otherwise we run a
+/// depth first search over every simple path, which needs the graph to have
+/// at most 64 nodes so that visited sets fit in a `u64`. The search is pruned
+/// by an upper bound on the length still to come, and once the only node
+/// leading into the target is reached we go straight to the target, since
+/// any other path would lock it out.
+pub fn longest_path<L, W>(
+    graph: &Graph<L, W>,
+    source: usize,
+    target: usize,
+) -> Option<Path<usize, u64>>
+where
+    L: Clone + Eq + Hash,
+    W: Clone + EdgeWeight,
+{
+    let edges = get_edges(graph);
+    if let Option::Some(order) = get_topological_order(&edges) {
+        return longest_acyclic_path(&edges, &order, source, target);
+    }
+    assert!(
+        graph.len() <= 64,
+        "longest paths in cyclic graphs need at most 64 nodes"
+    );
+    let mut search = LongestPathSearch::new(edges, target);
+    return search.execute(source);
+}
+
+/// Outgoing edges of each node, keeping only the longest edge between two
+/// nodes and dropping loops.
+fn get_edges<L, W>(graph: &Graph<L, W>) -> Vec<Vec<(usize, u64)>>
+where
+    L: Clone + Eq + Hash,
+    W: Clone + EdgeWeight,
+{
+    return graph
+        .indices()
+        .map(|from| {
+            let mut edges: Vec<(usize, u64)> = vec![];
+            for (to, weight) in graph.edges(from) {
+                if *to == from {
+                    continue;
+                }
+                let weight = weight.weight();
+                match edges.iter_mut().find(|(next, _)| next == to) {
+                    Option::Some(edge) => edge.1 = edge.1.max(weight),
+                    Option::None => edges.push((*to, weight)),
+                }
+            }
+            return edges;
+        })
+        .collect();
+}
+
+/// Kahn's algorithm. Returns `None` if the graph has a cycle.
+fn get_topological_order(edges: &Vec<Vec<(usize, u64)>>) -> Option<Vec<usize>> {
+    let mut in_degrees = vec![0; edges.len()];
+    for node_edges in edges.iter() {
+        for (to, _) in node_edges.iter() {
+            in_degrees[*to] += 1;
+        }
+    }
+    let mut order: Vec<usize> = (0..edges.len()).filter(|&i| in_degrees[i] == 0).collect();
+    let mut curr = 0;
+    while curr < order.len() {
+        for (to, _) in edges[order[curr]].iter() {
+            in_degrees[*to] -= 1;
+            if in_degrees[*to] == 0 {
+                order.push(*to);
+            }
+        }
+        curr += 1;
+    }
+    if order.len() < edges.len() {
+        return Option::None;
+    }
+    return Option::Some(order);
+}
+
+fn longest_acyclic_path(
+    edges: &Vec<Vec<(usize, u64)>>,
+    order: &Vec<usize>,
+    source: usize,
+    target: usize,
+) -> Option<Path<usize, u64>> {
+    // Longest length to the target from each node, and the next node on it.
+    let mut lengths: Vec<Option<(u64, usize)>> = vec![Option::None; edges.len()];
+    lengths[target] = Option::Some((0, target));
+    for &node in order.iter().rev() {
+        if node == target {
+            continue;
+        }
+        for &(next, weight) in edges[node].iter() {
+            if let Option::Some((length, _)) = lengths[next] {
+                if lengths[node].map_or(true, |(best, _)| length + weight > best) {
+                    lengths[node] = Option::Some((length + weight, next));
+                }
+            }
+        }
+    }
+
+    let (cost, _) = lengths[source]?;
+    let mut states = vec![source];
+    let mut curr = source;
+    while curr != target {
+        curr = lengths[curr].unwrap().1;
+        states.push(curr);
+    }
+    return Option::Some(Path { cost, states });
+}
+
+struct LongestPathSearch {
+    edges: Vec<Vec<(usize, u64)>>,
+    target: usize,
+    /// Longest edge leaving each node, used to bound the remaining length.
+    max_lengths: Vec<u64>,
+    /// The only node leading into the target, with the length of that edge.
+    last_edge: Option<(usize, u64)>,
+    path: Vec<usize>,
+    best: Option<Path<usize, u64>>,
+}
+
+impl LongestPathSearch {
+    fn new(edges: Vec<Vec<(usize, u64)>>, target: usize) -> Self {
+        let max_lengths = edges
+            .iter()
+            .map(|node_edges| {
+                node_edges
+                    .iter()
+                    .map(|(_, weight)| *weight)
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let mut last_edges = (0..edges.len()).filter_map(|node| {
+            return edges[node]
+                .iter()
+                .find(|(next, _)| *next == target)
+                .map(|(_, weight)| (node, *weight));
+        });
+        let last_edge = match (last_edges.next(), last_edges.next()) {
+            (Option::Some(last_edge), Option::None) => Option::Some(last_edge),
+            _ => Option::None,
+        };
+        return Self {
+            edges,
+            target,
+            max_lengths,
+            last_edge,
+            path: vec![],
+            best: Option::None,
+        };
+    }
+
+    fn execute(&mut self, source: usize) -> Option<Path<usize, u64>> {
+        let bound = (0..self.edges.len())
+            .filter(|&node| node != source && node != self.target)
+            .map(|node| self.max_lengths[node])
+            .sum();
+        self.search(source, 1 << source, 0, bound);
+        return self.best.take();
+    }
+
+    /// `bound` is the sum of the longest edges leaving the nodes that are
+    /// neither visited nor the target, each of which adds at most one edge to
+    /// the rest of the path.
+    fn search(&mut self, node: usize, visited: u64, length: u64, bound: u64) {
+        self.path.push(node);
+        if node == self.target {
+            if self.best.as_ref().map_or(true, |best| length > best.cost) {
+                self.best = Option::Some(Path {
+                    cost: length,
+                    states: self.path.clone(),
+                });
+            }
+            self.path.pop();
+            return;
+        }
+        let upper_bound = length + self.max_lengths[node] + bound;
+        if self
+            .best
+            .as_ref()
+            .map_or(false, |best| upper_bound <= best.cost)
+        {
+            self.path.pop();
+            return;
+        }
+
+        if let Option::Some((last_node, weight)) = self.last_edge {
+            if node == last_node {
+                self.search(
+                    self.target,
+                    visited | 1 << self.target,
+                    length + weight,
+                    bound,
+                );
+                self.path.pop();
+                return;
+            }
+        }
+        for i in 0..self.edges[node].len() {
+            let (next, weight) = self.edges[node][i];
+            if visited & 1 << next != 0 {
+                continue;
+            }
+            let next_bound = if next == self.target {
+                bound
+            } else {
+                bound - self.max_lengths[next]
+            };
+            self.search(next, visited | 1 << next, length + weight, next_bound);
+        }
+        self.path.pop();
+    }
+}
@@ -0,0 +1,257 @@
+use crate::geom::{Direction4, Point2};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Rectangular grid stored row by row in a single vector. Positions are
+/// `(x, y)` pairs, with `x` the column and `y` the row, both starting at the
+/// top left corner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert!(cells.len() == width * height);
+        return Self {
+            width,
+            height,
+            cells,
+        };
+    }
+
+    /// Builds a grid from text lines, mapping every character to a cell.
+    /// Panics if the lines do not all have the same length.
+    pub fn from_lines<I, S, F>(lines: I, mut f: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: FnMut(char) -> T,
+    {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines {
+            let line = line.as_ref();
+            let start = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let row_width = cells.len() - start;
+            if height == 0 {
+                width = row_width;
+            }
+            assert!(row_width == width, "row {} has a different width", height);
+            height += 1;
+        }
+        return Self {
+            width,
+            height,
+            cells,
+        };
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        return x < self.width && y < self.height;
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        if !self.contains(position) {
+            return Option::None;
+        }
+        return Option::Some(&self.cells[self.offset(position)]);
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if !self.contains(position) {
+            return Option::None;
+        }
+        let offset = self.offset(position);
+        return Option::Some(&mut self.cells[offset]);
+    }
+
+    /// Signed lookup, for walks that may step off the grid.
+    pub fn get_signed(&self, (x, y): (i64, i64)) -> Option<&T> {
+        if x < 0 || y < 0 {
+            return Option::None;
+        }
+        return self.get((x as usize, y as usize));
+    }
+
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        assert!(self.contains(a), "{:?} is out of bounds", a);
+        assert!(self.contains(b), "{:?} is out of bounds", b);
+        let a = self.offset(a);
+        let b = self.offset(b);
+        self.cells.swap(a, b);
+    }
+
+    /// Position one step away in the given direction, if inside the grid.
+    pub fn step(&self, position: (usize, usize), direction: Direction4) -> Option<(usize, usize)> {
+        let next = Point2::from(position).step(direction)?.into();
+        if !self.contains(next) {
+            return Option::None;
+        }
+        return Option::Some(next);
+    }
+
+    /// Orthogonal neighbours inside the grid, in `Direction4::ALL` order.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction));
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid, row by row.
+    pub fn neighbours8(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width as i64;
+        let height = self.height as i64;
+        let (x, y) = (x as i64, y as i64);
+        return (-1..=1)
+            .flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+            .filter(move |&(nx, ny)| {
+                (nx, ny) != (x, y) && nx >= 0 && ny >= 0 && nx < width && ny < height
+            })
+            .map(|(nx, ny)| (nx as usize, ny as usize));
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[(y * self.width)..((y + 1) * self.width)];
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        return &mut self.cells[(y * self.width)..((y + 1) * self.width)];
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width);
+        return self.cells.iter().skip(x).step_by(self.width);
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return self.cells.chunks(self.width.max(1)).take(self.height);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(move |x| self.column(x));
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        return (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        return self.cells.iter();
+    }
+
+    /// Cells together with their positions, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    /// First position, row by row, whose cell matches the predicate.
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<(usize, usize)> {
+        return self
+            .cells
+            .iter()
+            .position(predicate)
+            .map(|offset| (offset % self.width, offset / self.width));
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        };
+    }
+
+    fn offset(&self, (x, y): (usize, usize)) -> usize {
+        return y * self.width + x;
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        return Self::new(width, height, vec![value; width * height]);
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+
+    pub fn transpose(&self) -> Self {
+        return self.remap(self.height, self.width, |(x, y)| (y, x));
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        return self.remap(self.height, self.width, |(x, y)| (y, height - 1 - x));
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+        return self.remap(self.height, self.width, |(x, y)| (width - 1 - y, x));
+    }
+
+    /// Builds a grid of the given size where each position takes the cell of
+    /// `source(position)` in this grid.
+    fn remap<F: Fn((usize, usize)) -> (usize, usize)>(
+        &self,
+        width: usize,
+        height: usize,
+        source: F,
+    ) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source((x, y))].clone());
+            }
+        }
+        return Self::new(width, height, cells);
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        assert!(self.contains(position), "{:?} is out of bounds", position);
+        return &self.cells[self.offset(position)];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        assert!(self.contains(position), "{:?} is out of bounds", position);
+        let offset = self.offset(position);
+        return &mut self.cells[offset];
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        return Result::Ok(());
+    }
+}
@@ -0,0 +1,66 @@
+use std::{error, fmt};
+
+use num::{BigRational, Zero};
+
+/// The system has no unique solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SingularMatrix;
+
+impl fmt::Display for SingularMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "the matrix is singular");
+    }
+}
+
+impl error::Error for SingularMatrix {}
+
+/// Solves `matrix * x = vector` by Gaussian elimination. The matrix must be
+/// square and is given row by row. Entries are arbitrary precision ratios, so
+/// every operation is exact and can't overflow: integer systems have to be
+/// converted first, as their solutions need not be integers.
+#[allow(clippy::needless_range_loop)]
+pub fn solve(
+    matrix: &Vec<Vec<BigRational>>,
+    vector: &Vec<BigRational>,
+) -> Result<Vec<BigRational>, SingularMatrix> {
+    let size = matrix.len();
+    assert!(vector.len() == size, "the vector does not match the matrix");
+    // Augmented matrix
+    let mut rows = matrix
+        .iter()
+        .zip(vector.iter())
+        .map(|(row, value)| {
+            assert!(row.len() == size, "the matrix is not square");
+            let mut row = row.clone();
+            row.push(value.clone());
+            return row;
+        })
+        .collect::<Vec<_>>();
+
+    for col in 0..size {
+        let pivot = (col..size)
+            .find(|&row| !rows[row][col].is_zero())
+            .ok_or(SingularMatrix)?;
+        rows.swap(col, pivot);
+        for row in (col + 1)..size {
+            if rows[row][col].is_zero() {
+                continue;
+            }
+            let factor = &rows[row][col] / &rows[col][col];
+            for k in col..=size {
+                let value = &factor * &rows[col][k];
+                rows[row][k] -= value;
+            }
+        }
+    }
+
+    let mut solution = vec![BigRational::zero(); size];
+    for row in (0..size).rev() {
+        let mut value = rows[row][size].clone();
+        for k in (row + 1)..size {
+            value -= &rows[row][k] * &solution[k];
+        }
+        solution[row] = value / &rows[row][row];
+    }
+    return Result::Ok(solution);
+}
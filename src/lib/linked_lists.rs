@@ -18,6 +18,7 @@ pub struct SinglyLinkedListNodeData<T> {
 }
 
 impl<T: Debug> SinglyLinkedList<T> {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> SinglyLinkedList<T> {
         return SinglyLinkedList {
             head: Option::None,
@@ -48,6 +49,7 @@ impl<T: Debug> SinglyLinkedList<T> {
         self.tail = Option::Some(node);
     }
 
+    #[allow(clippy::question_mark)]
     pub fn pop(&mut self) -> Option<T> {
         if self.head.is_none() {
             return Option::None;
@@ -68,6 +70,7 @@ impl<T: Debug> SinglyLinkedList<T> {
         return self.head.is_none();
     }
 
+    #[allow(clippy::map_clone)]
     pub fn get_head(&self) -> Option<SinglyLinkedListNode<T>> {
         return self.head.as_ref().map(|n| n.clone());
     }
@@ -0,0 +1,204 @@
+pub mod poly;
+
+use num::{Integer, Signed};
+
+use crate::checked::{Checked, Overflow};
+
+/// Returns `(g, x, y)` such that `g = gcd(a, b)` is non-negative and
+/// `a * x + b * y = g`.
+/// Source: https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm
+pub fn extended_gcd<T>(a: T, b: T) -> (T, T, T)
+where
+    T: Integer + Signed + Copy,
+{
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r.is_negative() {
+        return (-old_r, -old_x, -old_y);
+    }
+    return (old_r, old_x, old_y);
+}
+
+/// The numbers `x` with `x ≡ residue (mod modulus)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Congruence {
+    pub residue: u64,
+    pub modulus: u64,
+}
+
+impl Congruence {
+    pub fn new(value: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "the modulus must be positive");
+        return Self {
+            residue: value % modulus,
+            modulus,
+        };
+    }
+
+    /// The numbers that satisfy both congruences, which is a congruence
+    /// modulo the LCM of both moduli, or `None` if there are none. The moduli
+    /// need not be coprime. Fails if the LCM does not fit in a `u64`.
+    pub fn combine(&self, other: &Self) -> Result<Option<Self>, Overflow> {
+        let (gcd, x, _) = extended_gcd(self.modulus as i128, other.modulus as i128);
+        let difference = other.residue as i128 - self.residue as i128;
+        if difference % gcd != 0 {
+            return Result::Ok(Option::None);
+        }
+        let reduced_modulus = other.modulus as i128 / gcd;
+        let modulus =
+            u64::try_from(self.modulus as u128 * reduced_modulus as u128).map_err(|_| Overflow)?;
+        // Number of steps of `self.modulus` from `self.residue` to the
+        // solution. Both factors are below `reduced_modulus`, so their product
+        // fits in a `u128`.
+        let steps = (difference / gcd).rem_euclid(reduced_modulus) as u128
+            * x.rem_euclid(reduced_modulus) as u128
+            % reduced_modulus as u128;
+        let residue = self.residue as u128 + self.modulus as u128 * steps;
+        return Result::Ok(Option::Some(Self {
+            residue: residue as u64,
+            modulus,
+        }));
+    }
+}
+
+/// Chinese remainder theorem, generalized to moduli that are not coprime.
+/// Returns the numbers that satisfy every congruence, or `None` if there are
+/// none.
+/// Source: https://en.wikipedia.org/wiki/Chinese_remainder_theorem#Generalization_to_non-coprime_moduli
+pub fn crt(congruences: &[Congruence]) -> Result<Option<Congruence>, Overflow> {
+    let mut result = Congruence::new(0, 1);
+    for congruence in congruences.iter() {
+        match result.combine(congruence)? {
+            Option::Some(combined) => result = combined,
+            Option::None => return Result::Ok(Option::None),
+        }
+    }
+    return Result::Ok(Option::Some(result));
+}
+
+/// The times `offset + k * period` for every `k >= 0`. A period of zero
+/// stands for the single time `offset`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sequence {
+    pub offset: u64,
+    pub period: u64,
+}
+
+impl Sequence {
+    pub fn contains(&self, time: u64) -> bool {
+        if time < self.offset {
+            return false;
+        }
+        if self.period == 0 {
+            return time == self.offset;
+        }
+        return (time - self.offset) % self.period == 0;
+    }
+
+    /// Whether every time of `self` is also a time of `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        if !other.contains(self.offset) {
+            return false;
+        }
+        if self.period == 0 {
+            return true;
+        }
+        return other.period != 0 && self.period % other.period == 0;
+    }
+
+    /// The times in both sequences, which are again a sequence, or `None` if
+    /// there are none. Fails if the first common time or the period of the
+    /// result does not fit in a `u64`.
+    pub fn intersect(&self, other: &Self) -> Result<Option<Self>, Overflow> {
+        if self.period == 0 {
+            return Result::Ok(other.contains(self.offset).then_some(*self));
+        }
+        if other.period == 0 {
+            return Result::Ok(self.contains(other.offset).then_some(*other));
+        }
+
+        let congruence = Congruence::new(self.offset, self.period)
+            .combine(&Congruence::new(other.offset, other.period))?;
+        let congruence = match congruence {
+            Option::Some(congruence) => congruence,
+            Option::None => return Result::Ok(Option::None),
+        };
+        // Both sequences have started by the latest offset.
+        let start = self.offset.max(other.offset);
+        let mut offset = congruence.residue;
+        if start > offset {
+            let steps = (start - offset).div_ceil(congruence.modulus);
+            offset = (Checked::new(steps) * congruence.modulus + offset).get()?;
+        }
+        return Result::Ok(Option::Some(Self {
+            offset,
+            period: congruence.modulus,
+        }));
+    }
+}
+
+/// Earliest time in all of the sequences, or `None` if they never meet.
+/// Fails if the sequences only meet again after a `u64` would overflow.
+pub fn first_common_time(sequences: &[Sequence]) -> Result<Option<u64>, Overflow> {
+    let mut common = Sequence {
+        offset: 0,
+        period: 1,
+    };
+    for sequence in sequences.iter() {
+        match common.intersect(sequence)? {
+            Option::Some(intersection) => common = intersection,
+            Option::None => return Result::Ok(Option::None),
+        }
+    }
+    return Result::Ok(Option::Some(common.offset));
+}
+
+/// Integer square root: the largest `r` with `r * r <= n`.
+/// Source: https://en.wikipedia.org/wiki/Integer_square_root#Algorithm_using_Newton's_method
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method decreases towards the root from any start above it,
+    // such as this power of two.
+    let mut x: u128 = 1 << ((129 - n.leading_zeros()) / 2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Number of integers `x` with `x * (sum - x) > threshold`.
+///
+/// That is `x^2 - sum * x + threshold < 0`, or `(2x - sum)^2 < sum^2 - 4 *
+/// threshold`, so `2x - sum` ranges over the integers of the same parity as
+/// `sum` whose square is below the discriminant. Everything is done in
+/// integers, so discriminants that are perfect squares are handled exactly.
+pub fn count_products_above(sum: u64, threshold: u64) -> u64 {
+    let sum = sum as u128;
+    let threshold = threshold as u128;
+    if sum * sum <= 4 * threshold {
+        return 0;
+    }
+    let discriminant = sum * sum - 4 * threshold;
+    // Largest `y` with `y^2 < discriminant`, then lowered to the parity of `sum`
+    let mut max_offset = isqrt(discriminant - 1);
+    if (max_offset + sum) % 2 == 1 {
+        if max_offset == 0 {
+            return 0;
+        }
+        max_offset -= 1;
+    }
+    // The offsets are `-max_offset, 2 - max_offset, ..., max_offset`.
+    return (max_offset + 1) as u64;
+}
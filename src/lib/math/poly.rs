@@ -0,0 +1,124 @@
+use num::{BigInt, BigRational, One, Zero};
+
+use crate::checked::{Checked, Overflow};
+
+/// Rows of repeated differences of `values`, from the values themselves down
+/// to the first row that is all zeros, which is left out.
+fn get_differences(values: &Vec<i64>) -> Result<Vec<Vec<i64>>, Overflow> {
+    let mut rows = vec![values.clone()];
+    loop {
+        let row = rows.last().unwrap();
+        if row.iter().all(|value| *value == 0) {
+            rows.pop();
+            return Result::Ok(rows);
+        }
+        let next = (1..row.len())
+            .map(|i| (Checked::new(row[i]) - row[i - 1]).get())
+            .collect::<Result<_, _>>()?;
+        rows.push(next);
+    }
+}
+
+/// Next value of a sequence sampled at consecutive points, taking the
+/// polynomial of lowest degree through the values.
+pub fn extrapolate_forward(values: &Vec<i64>) -> Checked<i64> {
+    return match get_differences(values) {
+        Result::Ok(rows) => rows.iter().map(|row| row[row.len() - 1]).sum(),
+        Result::Err(Overflow) => Checked::overflowed(),
+    };
+}
+
+/// Value before the first one of a sequence sampled at consecutive points,
+/// taking the polynomial of lowest degree through the values.
+pub fn extrapolate_backward(values: &Vec<i64>) -> Checked<i64> {
+    let rows = match get_differences(values) {
+        Result::Ok(rows) => rows,
+        Result::Err(Overflow) => return Checked::overflowed(),
+    };
+    let mut extrapolated = Checked::new(0);
+    for row in rows.iter().rev() {
+        extrapolated = Checked::new(row[0]) - extrapolated;
+    }
+    return extrapolated;
+}
+
+/// Polynomial with exact rational coefficients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial {
+    /// Lowest degree first, without trailing zeros.
+    coefficients: Vec<BigRational>,
+}
+
+impl Polynomial {
+    pub fn new(mut coefficients: Vec<BigRational>) -> Self {
+        while coefficients.last().map_or(false, |c| c.is_zero()) {
+            coefficients.pop();
+        }
+        return Self { coefficients };
+    }
+
+    /// Lowest degree first.
+    pub fn coefficients(&self) -> &Vec<BigRational> {
+        return &self.coefficients;
+    }
+
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        return self.coefficients.len().checked_sub(1);
+    }
+
+    /// Lagrange interpolation: the polynomial of lowest degree through the
+    /// `(x, y)` points, whose `x` must be distinct.
+    /// Source: https://en.wikipedia.org/wiki/Lagrange_polynomial
+    pub fn interpolate(points: &Vec<(i64, i64)>) -> Self {
+        let mut coefficients = vec![BigRational::zero(); points.len()];
+        for i in 0..points.len() {
+            let (x, y) = points[i];
+            // Product of `X - other_x` over the other points
+            let mut basis = vec![BigInt::one()];
+            let mut denominator = BigInt::one();
+            for (j, &(other_x, _)) in points.iter().enumerate() {
+                if j == i {
+                    continue;
+                }
+                assert!(other_x != x, "the points must have distinct x");
+                let mut next = vec![BigInt::zero(); basis.len() + 1];
+                for k in 0..basis.len() {
+                    next[k + 1] += &basis[k];
+                    next[k] -= &basis[k] * other_x;
+                }
+                basis = next;
+                denominator *= x - other_x;
+            }
+            let factor = BigRational::new(BigInt::from(y), denominator);
+            for k in 0..basis.len() {
+                coefficients[k] += &factor * &basis[k];
+            }
+        }
+        return Self::new(coefficients);
+    }
+
+    /// Polynomial of degree at most `degree` through every point, or `None` if
+    /// there is none. Needs more than `degree` points.
+    pub fn fit(points: &Vec<(i64, i64)>, degree: usize) -> Option<Self> {
+        assert!(points.len() > degree, "not enough points to fit the degree");
+        let polynomial = Self::interpolate(&points[..=degree].to_vec());
+        let fits = points
+            .iter()
+            .all(|&(x, y)| polynomial.evaluate(&to_rational(x)) == to_rational(y));
+        return fits.then_some(polynomial);
+    }
+
+    /// Horner's method.
+    pub fn evaluate(&self, x: &BigRational) -> BigRational {
+        let mut value = BigRational::zero();
+        for coefficient in self.coefficients.iter().rev() {
+            value = value * x + coefficient;
+        }
+        return value;
+    }
+}
+
+fn to_rational(value: i64) -> BigRational {
+    return BigRational::from_integer(BigInt::from(value));
+}
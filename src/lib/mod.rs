@@ -1,3 +1,14 @@
+pub mod checked;
+pub mod cycle;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod linalg;
 pub mod linked_lists;
+pub mod math;
 pub mod reader;
+pub mod registry;
+pub mod search;
+pub mod solution;
 pub mod trie;
+pub mod y2023;
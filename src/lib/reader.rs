@@ -1,20 +1,231 @@
 use std::{
+    error, fmt,
     fs::File,
-    io::{BufRead, BufReader},
-    path::Path,
+    io::{self, BufRead, BufReader, Cursor},
+    path::PathBuf,
+    str::FromStr,
 };
 
-pub fn read_lines<P: AsRef<Path>>(path: P) -> impl Iterator<Item = String> {
-    let handler = File::open(path).unwrap();
-    let reader = BufReader::new(handler);
-    return reader.lines().map(|l| l.unwrap());
+use crate::grid::Grid;
+
+/// Where a solution reads its puzzle input from.
+#[derive(Clone, Debug)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Input {
+    /// The conventional location of a day's input, `data/YYYY/DD/input.txt`.
+    pub fn default_for(year: u16, day: u8) -> Self {
+        return Input::File(PathBuf::from(format!("data/{}/{:02}/input.txt", year, day)));
+    }
+
+    /// Interprets a command line argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            return Input::Stdin;
+        }
+        return Input::File(PathBuf::from(arg));
+    }
+
+    /// Text inputs are borrowed rather than copied.
+    fn open(&self) -> Result<Box<dyn BufRead + '_>, ReaderError> {
+        return match self {
+            Input::File(path) => match File::open(path) {
+                Result::Ok(handler) => Result::Ok(Box::new(BufReader::new(handler))),
+                Result::Err(error) => Result::Err(ReaderError::Open {
+                    input: self.to_string(),
+                    error,
+                }),
+            },
+            Input::Stdin => Result::Ok(Box::new(io::stdin().lock())),
+            Input::Text(text) => Result::Ok(Box::new(Cursor::new(text.as_bytes()))),
+        };
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Text(_) => write!(f, "<text>"),
+        };
+    }
+}
+
+/// Why an input could not be read. Line numbers are 1-based.
+#[derive(Debug)]
+pub enum ReaderError {
+    Open {
+        input: String,
+        error: io::Error,
+    },
+    Read {
+        input: String,
+        line: usize,
+        error: io::Error,
+    },
+    InvalidUtf8 {
+        input: String,
+        line: usize,
+    },
+    /// The input does not have the format the solution expects.
+    Malformed {
+        input: String,
+        line: usize,
+        message: String,
+    },
+}
+
+impl ReaderError {
+    pub fn malformed(input: &Input, line: usize, message: impl fmt::Display) -> Self {
+        return ReaderError::Malformed {
+            input: input.to_string(),
+            line,
+            message: message.to_string(),
+        };
+    }
+}
+
+impl fmt::Display for ReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ReaderError::Open { input, error } => write!(f, "cannot open {}: {}", input, error),
+            ReaderError::Read { input, line, error } => {
+                write!(f, "{}:{}: read failed: {}", input, line, error)
+            }
+            ReaderError::InvalidUtf8 { input, line } => {
+                write!(f, "{}:{}: invalid UTF-8", input, line)
+            }
+            ReaderError::Malformed {
+                input,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", input, line, message),
+        };
+    }
+}
+
+impl error::Error for ReaderError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            ReaderError::Open { error, .. } | ReaderError::Read { error, .. } => {
+                Option::Some(error)
+            }
+            ReaderError::InvalidUtf8 { .. } | ReaderError::Malformed { .. } => Option::None,
+        };
+    }
+}
+
+/// Reads the whole input as chunks separated by `delimiter`, keeping track of
+/// the line each chunk starts on so that errors can point at it.
+fn read_chunks(input: &Input, delimiter: u8) -> Result<Vec<String>, ReaderError> {
+    let mut reader = input.open()?;
+    let mut chunks = Vec::new();
+    let mut line = 1;
+    loop {
+        let mut buffer = Vec::new();
+        let bytes_read = match reader.read_until(delimiter, &mut buffer) {
+            Result::Ok(bytes_read) => bytes_read,
+            Result::Err(error) => {
+                return Result::Err(ReaderError::Read {
+                    input: input.to_string(),
+                    line,
+                    error,
+                });
+            }
+        };
+        if bytes_read == 0 {
+            break;
+        }
+        let chunk_line = line;
+        line += buffer.iter().filter(|b| **b == b'\n').count();
+        if buffer.last() == Option::Some(&delimiter) {
+            buffer.pop();
+        }
+        match String::from_utf8(buffer) {
+            Result::Ok(chunk) => chunks.push(chunk),
+            Result::Err(_) => {
+                return Result::Err(ReaderError::InvalidUtf8 {
+                    input: input.to_string(),
+                    line: chunk_line,
+                });
+            }
+        }
+    }
+    return Result::Ok(chunks);
+}
+
+pub fn read_lines(input: &Input) -> Result<impl Iterator<Item = String>, ReaderError> {
+    let lines = read_chunks(input, b'\n')?;
+    return Result::Ok(lines.into_iter().map(|mut line| {
+        if line.ends_with('\r') {
+            line.pop();
+        }
+        return line;
+    }));
+}
+
+pub fn read_split(
+    input: &Input,
+    split_char: char,
+) -> Result<impl Iterator<Item = String>, ReaderError> {
+    return Result::Ok(read_chunks(input, split_char as u8)?.into_iter());
+}
+
+/// Parses `text`, found on line `line` of `input`.
+pub fn parse_at<T: FromStr>(input: &Input, line: usize, text: &str) -> Result<T, ReaderError> {
+    return text
+        .parse()
+        .map_err(|_| ReaderError::malformed(input, line, format!("cannot parse `{}`", text)));
+}
+
+/// Builds a grid from `lines`, the first of which is line `first_line` of
+/// `input`, mapping every character with `f`. Fails if `f` rejects a
+/// character, if the lines do not all have the same length or if there are
+/// none.
+pub fn parse_grid<T, F>(
+    input: &Input,
+    first_line: usize,
+    lines: &[String],
+    mut f: F,
+) -> Result<Grid<T>, ReaderError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    if lines.is_empty() || lines[0].is_empty() {
+        return Result::Err(ReaderError::malformed(input, first_line, "empty grid"));
+    }
+    let width = lines[0].chars().count();
+    let mut cells = Vec::with_capacity(width * lines.len());
+    for (i, row) in lines.iter().enumerate() {
+        let line = first_line + i;
+        let start = cells.len();
+        for c in row.chars() {
+            match f(c) {
+                Option::Some(cell) => cells.push(cell),
+                Option::None => {
+                    let message = format!("unexpected character `{}`", c);
+                    return Result::Err(ReaderError::malformed(input, line, message));
+                }
+            }
+        }
+        if cells.len() - start != width {
+            let message = format!("expected {} characters", width);
+            return Result::Err(ReaderError::malformed(input, line, message));
+        }
+    }
+    return Result::Ok(Grid::new(width, lines.len(), cells));
 }
 
-pub fn read_split<P: AsRef<Path>>(path: P, split_char: char) -> impl Iterator<Item = String> {
-    let handler = File::open(path).unwrap();
-    let reader = BufReader::new(handler);
-    return reader
-        .split(split_char as u8)
-        .map(|bs| bs.unwrap())
-        .map(|bs| String::from_utf8(bs).unwrap());
+/// Reads the whole input as a grid, see `parse_grid`.
+pub fn read_grid<T, F>(input: &Input, f: F) -> Result<Grid<T>, ReaderError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let lines = read_lines(input)?.collect::<Vec<_>>();
+    return parse_grid(input, 1, &lines, f);
 }
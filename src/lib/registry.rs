@@ -0,0 +1,35 @@
+use crate::reader::Input;
+use crate::solution::{solve, Part, Run, Solution, SolveError};
+use crate::y2023;
+
+/// A single puzzle that the runner knows how to execute.
+#[derive(Clone, Copy)]
+#[allow(clippy::type_complexity)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&Input, &[(String, String)], &[Part]) -> Result<Run, SolveError>,
+}
+
+impl Entry {
+    pub fn new<S: Solution>(year: u16, day: u8) -> Self {
+        return Self {
+            year,
+            day,
+            solve: solve::<S>,
+        };
+    }
+
+    pub fn default_input(&self) -> Input {
+        return Input::default_for(self.year, self.day);
+    }
+}
+
+/// Every registered solution, sorted by year and day. New years plug in by
+/// exposing their own `get_entries` and being appended here.
+pub fn get_entries() -> Vec<Entry> {
+    let mut entries = Vec::new();
+    entries.extend(y2023::get_entries());
+    entries.sort_by_key(|entry| (entry.year, entry.day));
+    return entries;
+}
@@ -0,0 +1,95 @@
+use crate::grid::Grid;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Number of moves from the nearest source to every reachable state.
+pub fn distances<S, I, N>(
+    sources: impl IntoIterator<Item = S>,
+    mut neighbours: N,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+{
+    let mut distances: HashMap<S, usize> = HashMap::new();
+    let mut queue: VecDeque<S> = VecDeque::new();
+    for source in sources {
+        if distances.contains_key(&source) {
+            continue;
+        }
+        distances.insert(source.clone(), 0);
+        queue.push_back(source);
+    }
+
+    while let Option::Some(curr) = queue.pop_front() {
+        let next_distance = distances[&curr] + 1;
+        for next in neighbours(&curr) {
+            if distances.contains_key(&next) {
+                continue;
+            }
+            distances.insert(next.clone(), next_distance);
+            queue.push_back(next);
+        }
+    }
+
+    return distances;
+}
+
+/// Distance map of a grid where moves go to orthogonal neighbours whose cell
+/// is `passable`. Unreachable cells are `None`.
+pub fn grid_distances<T, P>(
+    grid: &Grid<T>,
+    sources: impl IntoIterator<Item = (usize, usize)>,
+    mut passable: P,
+) -> Grid<Option<usize>>
+where
+    P: FnMut(&T) -> bool,
+{
+    let mut distances: Grid<Option<usize>> = grid.map(|_| Option::None);
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+    for source in sources {
+        if distances[source].is_some() {
+            continue;
+        }
+        distances[source] = Option::Some(0);
+        queue.push_back(source);
+    }
+
+    while let Option::Some(curr) = queue.pop_front() {
+        let next_distance = distances[curr].unwrap() + 1;
+        for next in grid.neighbours4(curr) {
+            if distances[next].is_some() || !passable(&grid[next]) {
+                continue;
+            }
+            distances[next] = Option::Some(next_distance);
+            queue.push_back(next);
+        }
+    }
+
+    return distances;
+}
+
+pub fn count_at_most(distances: impl IntoIterator<Item = usize>, steps: usize) -> usize {
+    return distances.into_iter().filter(|&d| d <= steps).count();
+}
+
+/// Number of states where a walk of exactly `steps` moves can end. This
+/// assumes the graph is bipartite and moves can be undone, as on a grid, so
+/// that a state is reachable whenever it is close enough and of the same
+/// parity as `steps`.
+pub fn count_exactly(distances: impl IntoIterator<Item = usize>, steps: usize) -> usize {
+    return distances
+        .into_iter()
+        .filter(|&d| d <= steps && (steps - d) % 2 == 0)
+        .count();
+}
+
+/// Number of reachable states at an even and at an odd distance.
+pub fn count_by_parity(distances: impl IntoIterator<Item = usize>) -> [usize; 2] {
+    let mut counts = [0, 0];
+    for d in distances {
+        counts[d & 1] += 1;
+    }
+    return counts;
+}
@@ -0,0 +1,144 @@
+pub mod bfs;
+
+use num::Zero;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cheapest path found by a search, from one of the start states to a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn start(&self) -> &S {
+        return self.states.first().unwrap();
+    }
+
+    pub fn goal(&self) -> &S {
+        return self.states.last().unwrap();
+    }
+}
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    previous: Option<usize>,
+    closed: bool,
+}
+
+/// Dijkstra's algorithm from several start states. `neighbours` lists the
+/// states reachable in one move together with the cost of the move, which
+/// must not be negative. Returns the cheapest path to any state accepted by
+/// `is_goal`.
+pub fn dijkstra<S, C, I, N, G>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: N,
+    is_goal: G,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    return astar(starts, neighbours, |_| C::zero(), is_goal);
+}
+
+/// A* search. `heuristic` must be consistent: it never overestimates the
+/// remaining cost to a goal and never drops by more than the cost of a move.
+/// Otherwise the returned path may not be the cheapest.
+pub fn astar<S, C, I, N, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    N: FnMut(&S) -> I,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut nodes: Vec<Node<S, C>> = Vec::new();
+    let mut index_map: HashMap<S, usize> = HashMap::new();
+    /* States are only pushed once reached, and stale entries are skipped */
+    let mut queue: BinaryHeap<Reverse<(C, C, usize)>> = BinaryHeap::new();
+
+    for state in starts {
+        if index_map.contains_key(&state) {
+            continue;
+        }
+        let estimate = heuristic(&state);
+        index_map.insert(state.clone(), nodes.len());
+        queue.push(Reverse((estimate, C::zero(), nodes.len())));
+        nodes.push(Node {
+            state,
+            cost: C::zero(),
+            previous: Option::None,
+            closed: false,
+        });
+    }
+
+    while let Option::Some(Reverse((_, cost, index))) = queue.pop() {
+        if nodes[index].closed || cost > nodes[index].cost {
+            continue;
+        }
+        nodes[index].closed = true;
+        if is_goal(&nodes[index].state) {
+            return Option::Some(reconstruct_path(&nodes, index));
+        }
+
+        for (next, step_cost) in neighbours(&nodes[index].state) {
+            let alt = cost + step_cost;
+            let next_index = match index_map.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    let node = &mut nodes[next_index];
+                    if node.closed || alt >= node.cost {
+                        continue;
+                    }
+                    node.cost = alt;
+                    node.previous = Option::Some(index);
+                    next_index
+                }
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        cost: alt,
+                        previous: Option::Some(index),
+                        closed: false,
+                    });
+                    entry.insert(next_index);
+                    next_index
+                }
+            };
+            let estimate = alt + heuristic(&nodes[next_index].state);
+            queue.push(Reverse((estimate, alt, next_index)));
+        }
+    }
+
+    return Option::None;
+}
+
+fn reconstruct_path<S: Clone, C: Copy>(nodes: &Vec<Node<S, C>>, goal: usize) -> Path<S, C> {
+    let mut states = vec![];
+    let mut curr = Option::Some(goal);
+    while let Option::Some(index) = curr {
+        states.push(nodes[index].state.clone());
+        curr = nodes[index].previous;
+    }
+    states.reverse();
+    return Path {
+        cost: nodes[goal].cost,
+        states,
+    };
+}
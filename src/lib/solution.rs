@@ -0,0 +1,275 @@
+use std::{
+    error, fmt,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::checked::{Checked, Overflow};
+use crate::reader::{Input, ReaderError};
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// Used for parts that have nothing to compute, e.g. the last day of a
+    /// year, or that have no answer for the input.
+    Empty,
+    /// The answer does not fit in an `i64`. `solve` reports it as an error.
+    Overflow,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Empty => write!(f, "-"),
+            Answer::Overflow => write!(f, "overflow"),
+        };
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        return Answer::Integer(value);
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        return match i64::try_from(value) {
+            Result::Ok(value) => Answer::Integer(value),
+            Result::Err(_) => Answer::Overflow,
+        };
+    }
+}
+
+impl<T: TryInto<i64>> From<Checked<T>> for Answer {
+    fn from(value: Checked<T>) -> Self {
+        return match value.get().map(T::try_into) {
+            Result::Ok(Result::Ok(value)) => Answer::Integer(value),
+            _ => Answer::Overflow,
+        };
+    }
+}
+
+/// `None` stands for an input that has no answer.
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        return value.map_or(Answer::Empty, T::into);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        return Answer::Text(value);
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        return Answer::Text(value.to_owned());
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        };
+    }
+}
+
+/// Tunable constants of a solution, such as a step count that the examples
+/// change. `Default` gives the values used by the actual puzzle.
+pub trait Params: Default {
+    /// Overrides the parameter called `name` with `value`.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+}
+
+impl Params for () {
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+        return Result::Err(ParamError::Unknown {
+            name: name.to_owned(),
+        });
+    }
+}
+
+/// Implements `Params` for a struct of parameters, each named after its field
+/// and parsed with `parse_param`, e.g.
+/// `impl_params!(Day21Params { steps, total_steps });`.
+macro_rules! impl_params {
+    ($params:ty { $($field:ident),+ $(,)? }) => {
+        impl $crate::solution::Params for $params {
+            fn set(
+                &mut self,
+                name: &str,
+                value: &str,
+            ) -> Result<(), $crate::solution::ParamError> {
+                match name {
+                    $(
+                        stringify!($field) => {
+                            self.$field = $crate::solution::parse_param(name, value)?
+                        }
+                    )+
+                    _ => {
+                        return Result::Err($crate::solution::ParamError::Unknown {
+                            name: name.to_owned(),
+                        })
+                    }
+                }
+                return Result::Ok(());
+            }
+        }
+    };
+}
+
+pub(crate) use impl_params;
+
+/// Parses `value` as the parameter called `name`.
+pub fn parse_param<T: FromStr>(name: &str, value: &str) -> Result<T, ParamError> {
+    return value.parse().map_err(|_| ParamError::Invalid {
+        name: name.to_owned(),
+        value: value.to_owned(),
+    });
+}
+
+#[derive(Debug)]
+pub enum ParamError {
+    Unknown { name: String },
+    Invalid { name: String, value: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ParamError::Unknown { name } => write!(f, "unknown parameter `{}`", name),
+            ParamError::Invalid { name, value } => {
+                write!(f, "invalid value `{}` for parameter `{}`", value, name)
+            }
+        };
+    }
+}
+
+impl error::Error for ParamError {}
+
+/// Why a solution could not produce its answers.
+#[derive(Debug)]
+pub enum SolveError {
+    Reader(ReaderError),
+    Param(ParamError),
+    /// The answer to the given part overflowed.
+    Overflow(Part),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            SolveError::Reader(error) => write!(f, "{}", error),
+            SolveError::Param(error) => write!(f, "{}", error),
+            SolveError::Overflow(part) => write!(f, "part {}: {}", part, Overflow),
+        };
+    }
+}
+
+impl error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            SolveError::Reader(error) => Option::Some(error),
+            SolveError::Param(error) => Option::Some(error),
+            SolveError::Overflow(_) => Option::Some(&Overflow),
+        };
+    }
+}
+
+impl From<ReaderError> for SolveError {
+    fn from(error: ReaderError) -> Self {
+        return SolveError::Reader(error);
+    }
+}
+
+impl From<ParamError> for SolveError {
+    fn from(error: ParamError) -> Self {
+        return SolveError::Param(error);
+    }
+}
+
+/// A puzzle solution. The input is parsed once and shared by both parts.
+pub trait Solution {
+    type Parsed;
+    type Params: Params;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError>;
+    fn part1(parsed: &Self::Parsed, params: &Self::Params) -> Answer;
+    fn part2(parsed: &Self::Parsed, params: &Self::Params) -> Answer;
+
+    /// Facts behind the answer to `part` worth reporting next to it, such as
+    /// intermediate counts, as `(name, value)` pairs. None by default.
+    fn details(
+        _parsed: &Self::Parsed,
+        _params: &Self::Params,
+        _part: Part,
+    ) -> Vec<(String, String)> {
+        return Vec::new();
+    }
+}
+
+/// The answers of one run of a solution, with the wall-clock time each phase took.
+pub struct Run {
+    pub answers: Vec<Answer>,
+    pub parse_time: Duration,
+    /// Indexed like `answers`.
+    pub part_times: Vec<Duration>,
+    /// Indexed like `answers`, see `Solution::details`.
+    pub details: Vec<Vec<(String, String)>>,
+}
+
+/// Parses `input` with `S` and computes the requested parts, in order, with
+/// the given `(name, value)` parameter overrides applied on top of the defaults.
+/// Answers that overflow are reported as errors.
+pub fn solve<S: Solution>(
+    input: &Input,
+    overrides: &[(String, String)],
+    parts: &[Part],
+) -> Result<Run, SolveError> {
+    let mut params = S::Params::default();
+    for (name, value) in overrides.iter() {
+        params.set(name, value)?;
+    }
+
+    let start_time = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start_time.elapsed();
+
+    let mut answers = Vec::with_capacity(parts.len());
+    let mut part_times = Vec::with_capacity(parts.len());
+    let mut details = Vec::with_capacity(parts.len());
+    for part in parts.iter() {
+        let start_time = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&parsed, &params),
+            Part::Two => S::part2(&parsed, &params),
+        };
+        part_times.push(start_time.elapsed());
+        if answer == Answer::Overflow {
+            return Result::Err(SolveError::Overflow(*part));
+        }
+        answers.push(answer);
+        details.push(S::details(&parsed, &params, *part));
+    }
+
+    return Result::Ok(Run {
+        answers,
+        parse_time,
+        part_times,
+        details,
+    });
+}
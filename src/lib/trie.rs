@@ -19,6 +19,7 @@ pub struct TriePointer {
 }
 
 impl Trie {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Trie {
         let root = TrieNode::new(0 as char, Option::None);
         let mut nodes = Vec::with_capacity(NODE_CAPACITY);
@@ -0,0 +1,147 @@
+use crate::reader::{read_lines, Input, ReaderError};
+use crate::solution::{Answer, Part, Solution};
+use crate::trie::Trie;
+
+const DIGITS: [(&str, i64); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const SPELLED_DIGITS: [(&str, i64); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+fn build_trie(spelled: bool) -> Trie {
+    let mut trie = Trie::new();
+    for (word, value) in DIGITS.iter() {
+        trie.insert(word.to_string(), *value);
+    }
+    if spelled {
+        for (word, value) in SPELLED_DIGITS.iter() {
+            trie.insert(word.to_string(), *value);
+        }
+    }
+    return trie;
+}
+
+#[allow(clippy::question_mark)]
+fn search_digits(trie: &Trie, char_vec: &Vec<char>, pos: usize) -> Option<i64> {
+    let mut offset: usize = 0;
+    let mut current_pointer = trie.get_root();
+
+    while pos + offset < char_vec.len() {
+        let char = char_vec[pos + offset];
+        let maybe_child_pointer = trie.get_child(&current_pointer, char);
+        if maybe_child_pointer.is_none() {
+            return Option::None;
+        }
+
+        let child_pointer = maybe_child_pointer.unwrap();
+        let value = trie.get_value(&child_pointer);
+        if value.is_some() {
+            return value;
+        }
+
+        offset += 1;
+        current_pointer = child_pointer;
+    }
+
+    return Option::None;
+}
+
+/// The first and last digits of the line, if it has any.
+fn get_digits(trie: &Trie, line: &str) -> Option<(i64, i64)> {
+    let char_vec: Vec<_> = line.chars().collect();
+
+    let mut first_digit: Option<i64> = Option::None;
+    let mut second_digit: Option<i64> = Option::None;
+
+    for pos in 0..char_vec.len() {
+        let value = search_digits(trie, &char_vec, pos);
+        if value.is_some() {
+            if first_digit.is_none() {
+                first_digit = value;
+            }
+            second_digit = value;
+        }
+    }
+
+    return Option::Some((first_digit?, second_digit?));
+}
+
+/// Every line must have a digit, see `parse`.
+fn get_value(spelled: bool, lines: &Vec<String>) -> Answer {
+    let mut total: i64 = 0;
+    let trie = build_trie(spelled);
+    for line in lines.iter() {
+        let (first_digit, second_digit) = get_digits(&trie, line).unwrap();
+        total += first_digit * 10 + second_digit;
+    }
+    return total.into();
+}
+
+pub enum Day01 {}
+
+impl Solution for Day01 {
+    /// The lines, with the first one that only has spelled digits, which part 1
+    /// can't read.
+    type Parsed = (Vec<String>, Option<usize>);
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let lines = read_lines(input)?.collect::<Vec<_>>();
+        let trie = build_trie(true);
+        for (i, line) in lines.iter().enumerate() {
+            if get_digits(&trie, line).is_none() {
+                return Result::Err(ReaderError::malformed(input, i + 1, "no digits"));
+            }
+        }
+        let spelled_only = lines
+            .iter()
+            .position(|line| !line.chars().any(|c| c.is_ascii_digit()))
+            .map(|i| i + 1);
+        return Result::Ok((lines, spelled_only));
+    }
+
+    /// Empty if a line only has spelled digits.
+    fn part1((lines, spelled_only): &Self::Parsed, _params: &Self::Params) -> Answer {
+        if spelled_only.is_some() {
+            return Answer::Empty;
+        }
+        return get_value(false, lines);
+    }
+
+    fn part2((lines, _): &Self::Parsed, _params: &Self::Params) -> Answer {
+        return get_value(true, lines);
+    }
+
+    /// The line that leaves part 1 without an answer.
+    fn details(
+        (_, spelled_only): &Self::Parsed,
+        _params: &Self::Params,
+        part: Part,
+    ) -> Vec<(String, String)> {
+        return match (part, spelled_only) {
+            (Part::One, Option::Some(line)) => {
+                vec![("no digits".to_owned(), format!("line {}", line))]
+            }
+            _ => Vec::new(),
+        };
+    }
+}
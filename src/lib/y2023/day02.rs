@@ -0,0 +1,109 @@
+use std::cmp::max;
+
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+pub enum Day02 {}
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_lines(input)?
+            .enumerate()
+            .map(|(i, line)| parse_game(input, i + 1, &line))
+            .collect();
+    }
+
+    fn part1(games: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut total: i64 = 0;
+        let limits: [i64; 3] = [12, 13, 14];
+        for game in games.iter() {
+            let maybe_value = get_id_if_possible(&limits, game);
+            if let Some(value) = maybe_value {
+                total += value;
+            }
+        }
+        return total.into();
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn part2(games: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut total: i64 = 0;
+        for game in games.iter() {
+            let mut item: i64 = 1;
+            let values = get_minimum_required(game);
+            for idx in 0..3 {
+                item *= values[idx];
+            }
+            total += item;
+        }
+        return total.into();
+    }
+}
+
+pub struct Game {
+    id: i64,
+    /// Amount of red, green and blue cubes shown in each round.
+    rounds: Vec<[i64; 3]>,
+}
+
+fn parse_game(input: &Input, line: usize, game: &str) -> Result<Game, ReaderError> {
+    let (label, rounds) = game
+        .split_once(": ")
+        .ok_or_else(|| ReaderError::malformed(input, line, "expected `Game <id>: <rounds>`"))?;
+    let id = match label.strip_prefix("Game ") {
+        Option::Some(id) => parse_at(input, line, id)?,
+        Option::None => {
+            return Result::Err(ReaderError::malformed(input, line, "expected `Game <id>`"))
+        }
+    };
+    let rounds = rounds
+        .split(';')
+        .map(|round| parse_round(input, line, round))
+        .collect::<Result<_, _>>()?;
+    return Result::Ok(Game { id, rounds });
+}
+
+fn parse_round(input: &Input, line: usize, round: &str) -> Result<[i64; 3], ReaderError> {
+    let mut result = [0; 3];
+    for information in round.split(',').map(|s| s.trim()) {
+        let (amount, color) = information
+            .split_once(' ')
+            .ok_or_else(|| ReaderError::malformed(input, line, "expected `<amount> <color>`"))?;
+        let color_key = match color {
+            "red" => 0,
+            "green" => 1,
+            "blue" => 2,
+            _ => {
+                let message = format!("unknown color `{}`", color);
+                return Result::Err(ReaderError::malformed(input, line, message));
+            }
+        };
+        result[color_key] = parse_at(input, line, amount)?;
+    }
+    return Result::Ok(result);
+}
+
+fn get_id_if_possible(limits: &[i64; 3], game: &Game) -> Option<i64> {
+    for round in game.rounds.iter() {
+        for idx in 0..3 {
+            if round[idx] > limits[idx] {
+                return Option::None;
+            }
+        }
+    }
+
+    return Option::Some(game.id);
+}
+
+fn get_minimum_required(game: &Game) -> [i64; 3] {
+    let mut result = [0; 3];
+    for round in game.rounds.iter() {
+        for idx in 0..3 {
+            result[idx] = max(result[idx], round[idx]);
+        }
+    }
+    return result;
+}
@@ -1,13 +1,6 @@
-extern crate lib;
-
-use lib::reader::read_lines;
-
-const GRID_WIDTH: usize = 140;
-
-fn main() {
-    first();
-    second();
-}
+use crate::grid::Grid;
+use crate::reader::{read_grid, Input, ReaderError};
+use crate::solution::{Answer, Solution};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 enum SelectedLine {
@@ -64,13 +57,14 @@ struct GearStats {
 }
 
 impl GearStats {
-    pub fn get_empty_line() -> Vec<GearStats> {
-        return vec![GearStats { count: 0, value: 1 }; GRID_WIDTH];
+    pub fn get_empty_line(width: usize) -> Vec<GearStats> {
+        return vec![GearStats { count: 0, value: 1 }; width];
     }
 }
 
 struct SecondConsumer {
     pub total: i64,
+    width: usize,
     prev_line: Vec<GearStats>,
     curr_line: Vec<GearStats>,
     next_line: Vec<GearStats>,
@@ -78,12 +72,13 @@ struct SecondConsumer {
 }
 
 impl SecondConsumer {
-    pub fn new() -> SecondConsumer {
+    pub fn new(width: usize) -> SecondConsumer {
         return SecondConsumer {
             total: 0,
-            prev_line: GearStats::get_empty_line(),
-            curr_line: GearStats::get_empty_line(),
-            next_line: GearStats::get_empty_line(),
+            width,
+            prev_line: GearStats::get_empty_line(width),
+            curr_line: GearStats::get_empty_line(width),
+            next_line: GearStats::get_empty_line(width),
             marked_positions: Vec::with_capacity(16),
         };
     }
@@ -120,7 +115,8 @@ impl Consumer for SecondConsumer {
     fn consume_line(&mut self) {
         std::mem::swap(&mut self.prev_line, &mut self.curr_line);
         std::mem::swap(&mut self.curr_line, &mut self.next_line);
-        let prev_line = std::mem::replace(&mut self.next_line, GearStats::get_empty_line());
+        let prev_line =
+            std::mem::replace(&mut self.next_line, GearStats::get_empty_line(self.width));
         for item in prev_line.iter() {
             if item.count == 2 {
                 self.total += item.value;
@@ -138,28 +134,39 @@ impl Consumer for SecondConsumer {
     }
 }
 
-fn first() {
-    let mut consumer = FirstConsumer::new();
-    iterate(&mut consumer);
-    println!("{}", consumer.total);
-}
+pub enum Day03 {}
+
+impl Solution for Day03 {
+    type Parsed = Grid<char>;
+    type Params = ();
 
-fn second() {
-    let mut consumer = SecondConsumer::new();
-    iterate(&mut consumer);
-    println!("{}", consumer.total);
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_grid(input, Option::Some);
+    }
+
+    fn part1(schematic: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut consumer = FirstConsumer::new();
+        iterate(&mut consumer, schematic);
+        return consumer.total.into();
+    }
+
+    fn part2(schematic: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut consumer = SecondConsumer::new(schematic.width());
+        iterate(&mut consumer, schematic);
+        return consumer.total.into();
+    }
 }
 
-fn iterate<C: Consumer>(consumer: &mut C) {
-    let mut maybe_prev_line: Option<String> = Option::None;
-    let mut line_iterator = read_lines("data/2023/03/input.txt").peekable();
+fn iterate<C: Consumer>(consumer: &mut C, schematic: &Grid<char>) {
+    let mut maybe_prev_line: Option<&[char]> = Option::None;
+    let mut line_iterator = schematic.rows().peekable();
 
     while let Some(curr_line) = line_iterator.next() {
         let maybe_next_line = line_iterator.peek();
         let mut curr_number: i64 = 0;
 
-        for pos in 0..GRID_WIDTH {
-            let curr_char = get_char_at_position(&curr_line, pos);
+        for pos in 0..curr_line.len() {
+            let curr_char = curr_line[pos];
             if !curr_char.is_ascii_digit() {
                 consumer.consume_number(curr_number);
                 curr_number = 0;
@@ -168,13 +175,13 @@ fn iterate<C: Consumer>(consumer: &mut C) {
 
             curr_number = curr_number * 10 + parse_ascii_digit(curr_char);
 
-            if let Some(ref prev_line) = maybe_prev_line {
+            if let Some(prev_line) = maybe_prev_line {
                 search_symbols_line(consumer, SelectedLine::Prev, prev_line, pos);
             }
-            if let Some(ref next_line) = maybe_next_line {
+            if let Some(next_line) = maybe_next_line {
                 search_symbols_line(consumer, SelectedLine::Next, next_line, pos);
             }
-            search_symbols_line(consumer, SelectedLine::Curr, &curr_line, pos);
+            search_symbols_line(consumer, SelectedLine::Curr, curr_line, pos);
         }
 
         consumer.consume_number(curr_number);
@@ -188,27 +195,23 @@ fn iterate<C: Consumer>(consumer: &mut C) {
 fn search_symbols_line<C: Consumer>(
     consumer: &mut C,
     selected_line: SelectedLine,
-    line: &str,
+    line: &[char],
     position: usize,
 ) {
     if position > 0 {
-        let prev_pos_char = get_char_at_position(&line, position - 1);
+        let prev_pos_char = line[position - 1];
         consumer.consume_symbol(selected_line, position - 1, prev_pos_char);
     }
 
     if position < line.len() - 1 {
-        let next_pos_char = get_char_at_position(&line, position + 1);
+        let next_pos_char = line[position + 1];
         consumer.consume_symbol(selected_line, position + 1, next_pos_char);
     }
 
-    let curr_pos_char = get_char_at_position(&line, position);
+    let curr_pos_char = line[position];
     consumer.consume_symbol(selected_line, position, curr_pos_char);
 }
 
-fn get_char_at_position(line: &str, position: usize) -> char {
-    return line.as_bytes()[position] as char;
-}
-
 fn parse_ascii_digit(char: char) -> i64 {
     return char as i64 - '0' as i64;
 }
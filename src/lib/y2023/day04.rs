@@ -0,0 +1,124 @@
+use crate::linked_lists::SinglyLinkedList;
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+trait Consumer {
+    fn consume_matches(&mut self, number: i64);
+}
+
+struct FirstConsumer {
+    pub total: i64,
+}
+
+impl FirstConsumer {
+    pub fn new() -> FirstConsumer {
+        return FirstConsumer { total: 0 };
+    }
+}
+
+impl Consumer for FirstConsumer {
+    fn consume_matches(&mut self, number: i64) {
+        if number == 0 {
+            return;
+        }
+        self.total += 1 << (number - 1);
+    }
+}
+
+struct SecondConsumer {
+    pub total: i64,
+    list: SinglyLinkedList<i64>,
+}
+
+impl SecondConsumer {
+    pub fn new() -> SecondConsumer {
+        return SecondConsumer {
+            total: 0,
+            list: SinglyLinkedList::new(),
+        };
+    }
+}
+
+impl Consumer for SecondConsumer {
+    fn consume_matches(&mut self, number: i64) {
+        let current = self.list.pop().unwrap_or(1);
+        self.total += current;
+        if number == 0 {
+            return;
+        }
+        if self.list.is_empty() {
+            self.list.insert_tail(1);
+        }
+        let mut node = self.list.get_head().unwrap();
+        for _ in 0..number {
+            node.set_data(node.get_data() + current);
+            if node.next().is_none() {
+                self.list.insert_tail(1);
+            }
+            node = node.next().unwrap();
+        }
+    }
+}
+
+/// Card numbers are stored as bits of a `u128`.
+fn parse_card_number(input: &Input, line: usize, text: &str) -> Result<u32, ReaderError> {
+    let number: u32 = parse_at(input, line, text)?;
+    if number >= u128::BITS {
+        let message = format!("card number {} is not below {}", number, u128::BITS);
+        return Result::Err(ReaderError::malformed(input, line, message));
+    }
+    return Result::Ok(number);
+}
+
+pub enum Day04 {}
+
+impl Solution for Day04 {
+    /// Number of winning numbers owned on each card.
+    type Parsed = Vec<i64>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let line_iterator = read_lines(input)?;
+        let mut matches = Vec::new();
+        for (i, line) in line_iterator.enumerate() {
+            let line_number = i + 1;
+            let (winning_numbers, owned_numbers) = line
+                .split_once(": ")
+                .and_then(|(_, numbers)| numbers.split_once(" | "))
+                .ok_or_else(|| {
+                    let message = "expected `Card <id>: <numbers> | <numbers>`";
+                    return ReaderError::malformed(input, line_number, message);
+                })?;
+            let mut winning_flag: u128 = 0;
+            let mut value: i64 = 0;
+            for item in winning_numbers.split_whitespace() {
+                winning_flag |= 1 << parse_card_number(input, line_number, item)?;
+            }
+            for item in owned_numbers.split_whitespace() {
+                if winning_flag & (1 << parse_card_number(input, line_number, item)?) != 0 {
+                    value += 1;
+                }
+            }
+            matches.push(value);
+        }
+        return Result::Ok(matches);
+    }
+
+    fn part1(matches: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut consumer = FirstConsumer::new();
+        iterate(&mut consumer, matches);
+        return consumer.total.into();
+    }
+
+    fn part2(matches: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut consumer = SecondConsumer::new();
+        iterate(&mut consumer, matches);
+        return consumer.total.into();
+    }
+}
+
+fn iterate<C: Consumer>(consumer: &mut C, matches: &Vec<i64>) {
+    for value in matches.iter() {
+        consumer.consume_matches(*value);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+const RANGES_CONTAINER_CAPACITY: usize = 8;
+const RANGES_CAPACITY: usize = 64;
+
+pub enum Day05 {}
+
+impl Solution for Day05 {
+    /// The seeds line, followed by each map as `(destination, source, length)` ranges.
+    type Parsed = (Vec<i64>, Vec<Vec<(i64, i64, i64)>>);
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_input(input);
+    }
+
+    fn part1((seeds, ranges_container): &Self::Parsed, _params: &Self::Params) -> Answer {
+        let result = seeds
+            .iter()
+            .map(|&v| (v, v))
+            .map(|r| propagate_range(ranges_container, r, 0, 0))
+            .min()
+            .unwrap();
+
+        return result.into();
+    }
+
+    /// Empty if the seeds don't come in pairs.
+    fn part2((seeds, ranges_container): &Self::Parsed, _params: &Self::Params) -> Answer {
+        if seeds.len() % 2 != 0 {
+            return Answer::Empty;
+        }
+        let result = seeds
+            .chunks(2)
+            .map(|chunk| {
+                return match chunk {
+                    &[a, b] => (a, a + b - 1),
+                    _ => unreachable!(),
+                };
+            })
+            .map(|r| propagate_range(ranges_container, r, 0, 0))
+            .min()
+            .unwrap();
+
+        return result.into();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn read_input(input: &Input) -> Result<(Vec<i64>, Vec<Vec<(i64, i64, i64)>>), ReaderError> {
+    let lines = read_lines(input)?.collect::<Vec<_>>();
+
+    let seeds_line = match lines.first().and_then(|line| line.strip_prefix("seeds: ")) {
+        Option::Some(seeds_line) => seeds_line,
+        Option::None => return Result::Err(ReaderError::malformed(input, 1, "expected `seeds: `")),
+    };
+    let seeds: Vec<i64> = seeds_line
+        .split_whitespace()
+        .map(|s| parse_at(input, 1, s))
+        .collect::<Result<_, _>>()?;
+    if seeds.is_empty() {
+        return Result::Err(ReaderError::malformed(input, 1, "expected seeds"));
+    }
+
+    let mut ranges_container: Vec<Vec<(i64, i64, i64)>> =
+        Vec::with_capacity(RANGES_CONTAINER_CAPACITY);
+
+    // Each map is a blank line, a header and its ranges.
+    let mut idx = 1;
+    while idx < lines.len() {
+        if !lines[idx].is_empty() {
+            return Result::Err(ReaderError::malformed(
+                input,
+                idx + 1,
+                "expected a blank line",
+            ));
+        }
+        if !lines
+            .get(idx + 1)
+            .map_or(false, |line| line.ends_with(" map:"))
+        {
+            return Result::Err(ReaderError::malformed(
+                input,
+                idx + 2,
+                "expected a map header",
+            ));
+        }
+        idx += 2;
+        let mut ranges: Vec<(i64, i64, i64)> = Vec::with_capacity(RANGES_CAPACITY);
+        while idx < lines.len() && !lines[idx].is_empty() {
+            let parts = lines[idx]
+                .split_whitespace()
+                .map(|s| parse_at(input, idx + 1, s))
+                .collect::<Result<Vec<i64>, _>>()?;
+            match parts[..] {
+                [first, second, third] => ranges.push((first, second, third)),
+                _ => {
+                    let message = "expected `<destination> <source> <length>`";
+                    return Result::Err(ReaderError::malformed(input, idx + 1, message));
+                }
+            }
+            idx += 1;
+        }
+        ranges_container.push(ranges);
+    }
+
+    return Result::Ok((seeds, ranges_container));
+}
+
+#[allow(clippy::needless_range_loop)]
+fn propagate_range(
+    ranges_container: &Vec<Vec<(i64, i64, i64)>>,
+    range: (i64, i64),
+    pos: usize,
+    start: usize,
+) -> i64 {
+    if pos >= ranges_container.len() {
+        return range.0;
+    }
+    let ranges = &ranges_container[pos];
+    for ridx in start..ranges.len() {
+        let transformation_range = ranges[ridx];
+        let lower_bound = transformation_range.1;
+        let upper_bound = transformation_range.1 + transformation_range.2 - 1;
+        if lower_bound <= range.0 && range.1 <= upper_bound {
+            let prop_range = get_propagated_range(range, transformation_range);
+            return propagate_range(ranges_container, prop_range, pos + 1, 0);
+        } else if range.0 <= lower_bound && lower_bound <= range.1 && range.1 <= upper_bound {
+            let stay_range = (range.0, lower_bound - 1);
+            let prop_range = get_propagated_range((lower_bound, range.1), transformation_range);
+            return std::cmp::min(
+                propagate_range(ranges_container, stay_range, pos, ridx + 1),
+                propagate_range(ranges_container, prop_range, pos + 1, 0),
+            );
+        } else if lower_bound <= range.0 && range.0 <= upper_bound && upper_bound <= range.1 {
+            let stay_range = (upper_bound + 1, range.1);
+            let prop_range = get_propagated_range((range.0, upper_bound), transformation_range);
+            return std::cmp::min(
+                propagate_range(ranges_container, stay_range, pos, ridx + 1),
+                propagate_range(ranges_container, prop_range, pos + 1, 0),
+            );
+        } else if range.0 <= lower_bound && upper_bound <= range.1 {
+            let left_range = (range.0, lower_bound - 1);
+            let prop_range = get_propagated_range((lower_bound, upper_bound), transformation_range);
+            let right_range = (upper_bound + 1, range.1);
+            return std::cmp::min(
+                propagate_range(ranges_container, left_range, pos, ridx + 1),
+                std::cmp::min(
+                    propagate_range(ranges_container, prop_range, pos + 1, 0),
+                    propagate_range(ranges_container, right_range, pos, ridx + 1),
+                ),
+            );
+        }
+    }
+
+    return propagate_range(ranges_container, range, pos + 1, 0);
+}
+
+fn get_propagated_range(range: (i64, i64), transformation_range: (i64, i64, i64)) -> (i64, i64) {
+    return (
+        transformation_range.0 + range.0 - transformation_range.1,
+        transformation_range.0 + range.1 - transformation_range.1,
+    );
+}
@@ -0,0 +1,131 @@
+use crate::checked::Checked;
+use crate::math::count_products_above;
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+/// Holding the button for `t` out of a race time `T` covers `(T - t) * t`,
+/// which has to beat the distance of the record holder.
+struct Solver {
+    pub total: Checked<u64>,
+}
+
+impl Solver {
+    pub fn new() -> Self {
+        return Self {
+            total: Checked::new(1),
+        };
+    }
+
+    fn consume_race(&mut self, race_time: u64, race_distance: u64) {
+        self.total *= count_products_above(race_time, race_distance);
+    }
+}
+
+/**
+ * FIXME: Can we make this trait work by returning iterators instead of vecs?
+ */
+trait Parser {
+    fn parse(&self, time_line: String, distance_line: String) -> Vec<(u64, u64)>;
+}
+
+struct FirstParser {}
+
+impl FirstParser {
+    pub fn new() -> Self {
+        return Self {};
+    }
+}
+
+impl Parser for FirstParser {
+    fn parse(&self, time_line: String, distance_line: String) -> Vec<(u64, u64)> {
+        let time_stream = time_line
+            .split_whitespace()
+            .map(|v| v.parse::<u64>().unwrap());
+        let distance_stream = distance_line
+            .split_whitespace()
+            .map(|v| v.parse::<u64>().unwrap());
+        return time_stream.zip(distance_stream).collect();
+    }
+}
+
+struct SecondParser {}
+
+impl SecondParser {
+    pub fn new() -> Self {
+        return Self {};
+    }
+}
+
+impl Parser for SecondParser {
+    fn parse(&self, time_line: String, distance_line: String) -> Vec<(u64, u64)> {
+        let time_data = time_line.replace(' ', "").parse::<u64>().unwrap();
+        let distance_data = distance_line.replace(' ', "").parse::<u64>().unwrap();
+        return vec![(time_data, distance_data)];
+    }
+}
+
+pub enum Day06 {}
+
+impl Solution for Day06 {
+    /// The time and distance lines, without their labels.
+    type Parsed = (String, String);
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let lines = read_lines(input)?.collect::<Vec<_>>();
+        let time_line = get_numbers_line(input, &lines, 1, "Time:")?;
+        let distance_line = get_numbers_line(input, &lines, 2, "Distance:")?;
+        let num_races = time_line.split_whitespace().count();
+        if distance_line.split_whitespace().count() != num_races {
+            let message = format!("expected {} distances", num_races);
+            return Result::Err(ReaderError::malformed(input, 2, message));
+        }
+        return Result::Ok((time_line, distance_line));
+    }
+
+    fn part1(lines: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let parser = FirstParser::new();
+        return iterate(&parser, lines).into();
+    }
+
+    fn part2(lines: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let parser = SecondParser::new();
+        return iterate(&parser, lines).into();
+    }
+}
+
+fn iterate<P: Parser>(parser: &P, (time_line, distance_line): &(String, String)) -> Checked<u64> {
+    let mut solver = Solver::new();
+    for (race_time, race_distance) in parser.parse(time_line.clone(), distance_line.clone()) {
+        solver.consume_race(race_time, race_distance);
+    }
+    return solver.total;
+}
+
+/// The numbers after `label` on line `line`, which must parse both one by one
+/// and joined into a single number.
+fn get_numbers_line(
+    input: &Input,
+    lines: &Vec<String>,
+    line: usize,
+    label: &str,
+) -> Result<String, ReaderError> {
+    let numbers = match lines
+        .get(line - 1)
+        .and_then(|text| text.strip_prefix(label))
+    {
+        Option::Some(numbers) => numbers.trim(),
+        Option::None => {
+            let message = format!("expected `{}`", label);
+            return Result::Err(ReaderError::malformed(input, line, message));
+        }
+    };
+    if numbers.is_empty() {
+        return Result::Err(ReaderError::malformed(input, line, "expected numbers"));
+    }
+    for number in numbers.split_whitespace() {
+        parse_at::<u64>(input, line, number)?;
+    }
+    parse_at::<u64>(input, line, &numbers.replace(' ', ""))?;
+    return Result::Ok(numbers.to_owned());
+}
@@ -1,11 +1,5 @@
-extern crate lib;
-
-use lib::reader::read_lines;
-
-pub fn main() {
-    first();
-    second();
-}
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
 
 trait Sorter {
     fn get_value_of_hand(&self, hand: &Vec<char>) -> i64;
@@ -58,7 +52,7 @@ impl Sorter for FirstSorter {
     }
 
     fn get_value_of_card(&self, card: char) -> i64 {
-        if card.is_digit(10) {
+        if card.is_ascii_digit() {
             return card as i64 - '0' as i64;
         }
 
@@ -86,11 +80,6 @@ impl Sorter for FirstSorter {
     }
 }
 
-fn first() {
-    let sorter = FirstSorter::new();
-    solve(&sorter);
-}
-
 struct SecondSorter {}
 
 impl SecondSorter {
@@ -119,7 +108,7 @@ impl Sorter for SecondSorter {
             }
         }
         counts.sort_by(|a, b| b.cmp(a));
-        if counts.len() == 0 {
+        if counts.is_empty() {
             counts.push(0);
         }
         counts[0] += wildcards;
@@ -136,9 +125,7 @@ impl Sorter for SecondSorter {
         if counts[0] == 3 {
             return 4;
         }
-        if counts[0] == 2
-            && counts[1] == 2
-        {
+        if counts[0] == 2 && counts[1] == 2 {
             return 3;
         }
         if counts[0] == 2 {
@@ -148,7 +135,7 @@ impl Sorter for SecondSorter {
     }
 
     fn get_value_of_card(&self, card: char) -> i64 {
-        if card.is_digit(10) {
+        if card.is_ascii_digit() {
             return card as i64 - '0' as i64;
         }
 
@@ -176,15 +163,33 @@ impl Sorter for SecondSorter {
     }
 }
 
-fn second() {
-    let sorter = SecondSorter::new();
-    solve(&sorter);
-}
+pub enum Day07 {}
+
+impl Solution for Day07 {
+    /// Each hand with its bid.
+    type Parsed = Vec<(Vec<char>, i64)>;
+    type Params = ();
 
-fn solve<S: Sorter>(sorter: &S) {
-    let line_iterator = read_lines("data/2023/07/input.txt");
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_lines(input)?
+            .enumerate()
+            .map(|(i, line)| parse_line(input, i + 1, &line))
+            .collect();
+    }
+
+    fn part1(data: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let sorter = FirstSorter::new();
+        return solve(&sorter, data).into();
+    }
 
-    let mut data: Vec<(Vec<char>, i64)> = line_iterator.map(parse_line).collect::<Vec<_>>();
+    fn part2(data: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let sorter = SecondSorter::new();
+        return solve(&sorter, data).into();
+    }
+}
+
+fn solve<S: Sorter>(sorter: &S, data: &Vec<(Vec<char>, i64)>) -> i64 {
+    let mut data = data.clone();
     data.sort_by_key(|(h, _)| get_sort_key(sorter, h));
 
     let result: i64 = data
@@ -193,20 +198,30 @@ fn solve<S: Sorter>(sorter: &S) {
         .map(|(idx, (_, b))| (idx as i64 + 1) * b)
         .sum();
 
-    println!("{}", result);
+    return result;
 }
 
-fn parse_line(line: String) -> (Vec<char>, i64) {
-    let h = line[..5].to_owned().chars().collect::<Vec<_>>();
-    let b = line[6..].parse::<i64>().unwrap();
-    return (h, b);
+const CARDS: &str = "23456789TJQKA";
+const HAND_SIZE: usize = 5;
+
+fn parse_line(input: &Input, line: usize, text: &str) -> Result<(Vec<char>, i64), ReaderError> {
+    let (hand, bid) = text
+        .split_once(' ')
+        .ok_or_else(|| ReaderError::malformed(input, line, "expected `<hand> <bid>`"))?;
+    let h = hand.chars().collect::<Vec<_>>();
+    if h.len() != HAND_SIZE || !h.iter().all(|&card| CARDS.contains(card)) {
+        let message = format!("expected {} cards out of `{}`", HAND_SIZE, CARDS);
+        return Result::Err(ReaderError::malformed(input, line, message));
+    }
+    let b = parse_at(input, line, bid)?;
+    return Result::Ok((h, b));
 }
 
 fn get_sort_key<S: Sorter>(sorter: &S, hand: &Vec<char>) -> i64 {
-    return sorter.get_value_of_hand(hand) * (10 as i64).pow(10)
-        + sorter.get_value_of_card(hand[0]) * (10 as i64).pow(8)
-        + sorter.get_value_of_card(hand[1]) * (10 as i64).pow(6)
-        + sorter.get_value_of_card(hand[2]) * (10 as i64).pow(4)
-        + sorter.get_value_of_card(hand[3]) * (10 as i64).pow(2)
+    return sorter.get_value_of_hand(hand) * 10_i64.pow(10)
+        + sorter.get_value_of_card(hand[0]) * 10_i64.pow(8)
+        + sorter.get_value_of_card(hand[1]) * 10_i64.pow(6)
+        + sorter.get_value_of_card(hand[2]) * 10_i64.pow(4)
+        + sorter.get_value_of_card(hand[3]) * 10_i64.pow(2)
         + sorter.get_value_of_card(hand[4]);
 }
@@ -0,0 +1,252 @@
+use crate::checked::Checked;
+use crate::cycle::find_cycle;
+use crate::graph::Graph;
+use crate::math::Sequence;
+use crate::reader::{read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+use itertools::Itertools;
+
+pub enum Day08 {}
+
+impl Solution for Day08 {
+    type Parsed = (Vec<Direction>, Network);
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let lines = read_lines(input)?.collect_vec();
+        let instructions = match lines.first() {
+            Option::Some(line) if !line.is_empty() => line
+                .chars()
+                .map(|c| {
+                    return Direction::from_char(c).ok_or_else(|| {
+                        return ReaderError::malformed(
+                            input,
+                            1,
+                            format!("unknown direction `{}`", c),
+                        );
+                    });
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Result::Err(ReaderError::malformed(input, 1, "expected directions")),
+        };
+
+        if lines.get(1).map_or(true, |line| !line.is_empty()) {
+            return Result::Err(ReaderError::malformed(input, 2, "expected a blank line"));
+        }
+        let network = Network::from_lines(input, 3, &lines[2..])?;
+        return Result::Ok((instructions, network));
+    }
+
+    fn part1((instructions, network): &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<FirstSolver>(instructions, network).into();
+    }
+
+    fn part2((instructions, network): &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<SecondSolver>(instructions, network).into();
+    }
+}
+
+enum FirstSolver {}
+
+impl Solver for FirstSolver {
+    fn get_start_positions(network: &Network) -> Vec<usize> {
+        return network.graph.index_of("AAA").into_iter().collect_vec();
+    }
+
+    fn get_can_stop(network: &Network) -> Vec<bool> {
+        let stop = network.graph.index_of("ZZZ");
+        return network
+            .graph
+            .indices()
+            .map(|i| Option::Some(i) == stop)
+            .collect_vec();
+    }
+}
+
+enum SecondSolver {}
+
+impl Solver for SecondSolver {
+    fn get_start_positions(network: &Network) -> Vec<usize> {
+        return network
+            .graph
+            .indices()
+            .filter(|i| network.graph.label(*i).ends_with('A'))
+            .collect_vec();
+    }
+
+    fn get_can_stop(network: &Network) -> Vec<bool> {
+        return network
+            .graph
+            .indices()
+            .map(|i| network.graph.label(i).ends_with('Z'))
+            .collect_vec();
+    }
+}
+
+/// Each node has two outgoing edges, left first and right second.
+#[derive(Debug)]
+pub struct Network {
+    graph: Graph<String>,
+}
+
+impl Network {
+    /// `first_line` is the line number of the first node in `input`. Every
+    /// node must be defined exactly once.
+    pub fn from_lines(
+        input: &Input,
+        first_line: usize,
+        lines: &[String],
+    ) -> Result<Self, ReaderError> {
+        let mut graph = Graph::new_directed();
+        // Line where each node was first seen
+        let mut node_lines: Vec<usize> = vec![];
+        for (i, line) in lines.iter().enumerate() {
+            let line_number = first_line + i;
+            let (source, left, right) = line
+                .split_once(" = ")
+                .and_then(|(source, destinations)| {
+                    let (left, right) = destinations
+                        .strip_prefix('(')?
+                        .strip_suffix(')')?
+                        .split_once(", ")?;
+                    return Option::Some((source, left, right));
+                })
+                .ok_or_else(|| {
+                    let message = "expected `<node> = (<left>, <right>)`";
+                    return ReaderError::malformed(input, line_number, message);
+                })?;
+            let source = graph.add_node(source.to_owned());
+            let left = graph.add_node(left.to_owned());
+            let right = graph.add_node(right.to_owned());
+            node_lines.resize(graph.len(), line_number);
+            if !graph.edges(source).is_empty() {
+                let message = format!("node `{}` is defined twice", graph.label(source));
+                return Result::Err(ReaderError::malformed(input, line_number, message));
+            }
+            graph.add_edge(source, left, ());
+            graph.add_edge(source, right, ());
+        }
+        for index in graph.indices() {
+            if graph.edges(index).is_empty() {
+                let message = format!("node `{}` is not defined", graph.label(index));
+                return Result::Err(ReaderError::malformed(input, node_lines[index], message));
+            }
+        }
+
+        return Result::Ok(Self { graph });
+    }
+
+    pub fn get_next(&self, index: usize, direction: Direction) -> usize {
+        let edges = self.graph.edges(index);
+        return match direction {
+            Direction::Left => edges[0].0,
+            Direction::Right => edges[1].0,
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn from_char(c: char) -> Option<Self> {
+        return match c {
+            'L' => Option::Some(Direction::Left),
+            'R' => Option::Some(Direction::Right),
+            _ => Option::None,
+        };
+    }
+}
+
+trait Solver {
+    fn get_start_positions(network: &Network) -> Vec<usize>;
+    fn get_can_stop(network: &Network) -> Vec<bool>;
+}
+
+/// Times at which a ghost leaving `start` is on a stop node, with one sequence
+/// per visit. The positions at the start of each pass over the instructions
+/// eventually cycle, and once they do so do the visits in between.
+fn get_stop_times(
+    network: &Network,
+    instructions: &Vec<Direction>,
+    can_stop: &Vec<bool>,
+    start: usize,
+) -> Vec<Sequence> {
+    let num_instructions = instructions.len();
+    let (cycle, pass_starts) = find_cycle(start, |position| {
+        return instructions.iter().fold(*position, |curr, direction| {
+            network.get_next(curr, *direction)
+        });
+    });
+    let prefix = (cycle.prefix * num_instructions) as u64;
+    let period = (cycle.period * num_instructions) as u64;
+
+    let mut stop_times = vec![];
+    for (pass, &pass_start) in pass_starts.iter().enumerate() {
+        let mut position = pass_start;
+        for (i, &direction) in instructions.iter().enumerate() {
+            let time = (pass * num_instructions + i) as u64;
+            if can_stop[position] {
+                if time >= prefix {
+                    // The ghost must take at least one step.
+                    let offset = if time == 0 { period } else { time };
+                    stop_times.push(Sequence { offset, period });
+                } else if time > 0 {
+                    stop_times.push(Sequence {
+                        offset: time,
+                        period: 0,
+                    });
+                }
+            }
+            position = network.get_next(position, direction);
+        }
+    }
+    return stop_times;
+}
+
+/// Every ghost moves at the same time, so we are looking for the first time
+/// shared by one stop sequence of each ghost. The times at which the ghosts so
+/// far are all on stop nodes are kept as a set of sequences, which is narrowed
+/// down one ghost at a time. `None` if the ghosts are never all on stop nodes.
+fn solve<S: Solver>(instructions: &Vec<Direction>, network: &Network) -> Option<Checked<u64>> {
+    let can_stop = S::get_can_stop(network);
+    let starts = S::get_start_positions(network);
+    if starts.is_empty() {
+        return Option::None;
+    }
+
+    let mut common = vec![Sequence {
+        offset: 0,
+        period: 1,
+    }];
+    for start in starts {
+        let stop_times = get_stop_times(network, instructions, &can_stop, start);
+        let mut next_common: Vec<Sequence> = vec![];
+        for sequence in common.iter() {
+            for stop_time in stop_times.iter() {
+                let intersection = match sequence.intersect(stop_time) {
+                    Result::Ok(Option::Some(intersection)) => intersection,
+                    Result::Ok(Option::None) => continue,
+                    Result::Err(_) => return Option::Some(Checked::overflowed()),
+                };
+                if next_common.iter().any(|kept| intersection.is_subset(kept)) {
+                    continue;
+                }
+                next_common.retain(|kept| !kept.is_subset(&intersection));
+                next_common.push(intersection);
+            }
+        }
+        if next_common.is_empty() {
+            return Option::None;
+        }
+        common = next_common;
+    }
+    return common
+        .iter()
+        .map(|sequence| sequence.offset)
+        .min()
+        .map(Checked::new);
+}
@@ -0,0 +1,55 @@
+use crate::checked::Checked;
+use crate::math::poly::{extrapolate_backward, extrapolate_forward};
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+pub enum Day09 {}
+
+impl Solution for Day09 {
+    type Parsed = Vec<Vec<i64>>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_lines(input)?
+            .enumerate()
+            .map(|(i, line)| {
+                return line
+                    .split(' ')
+                    .map(|x| parse_at(input, i + 1, x))
+                    .collect::<Result<Vec<i64>, _>>();
+            })
+            .collect();
+    }
+
+    fn part1(sequences: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<FirstSolver>(sequences).into();
+    }
+
+    fn part2(sequences: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<SecondSolver>(sequences).into();
+    }
+}
+
+enum FirstSolver {}
+
+impl Solver for FirstSolver {
+    fn extrapolate(values: &Vec<i64>) -> Checked<i64> {
+        return extrapolate_forward(values);
+    }
+}
+
+enum SecondSolver {}
+
+impl Solver for SecondSolver {
+    fn extrapolate(values: &Vec<i64>) -> Checked<i64> {
+        return extrapolate_backward(values);
+    }
+}
+
+trait Solver {
+    fn extrapolate(values: &Vec<i64>) -> Checked<i64>;
+}
+
+fn solve<S: Solver>(sequences: &Vec<Vec<i64>>) -> Checked<i64> {
+    return sequences.iter().map(S::extrapolate).sum();
+}
@@ -0,0 +1,180 @@
+use crate::geom::{Direction4, Directions4};
+use crate::grid::Grid;
+use crate::reader::{read_grid, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+pub enum Day10 {}
+
+impl Solution for Day10 {
+    type Parsed = PipeMap;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return PipeMap::read(input);
+    }
+
+    fn part1(pipe_map: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut solver = FirstSolver::new();
+        return solve(&mut solver, pipe_map).into();
+    }
+
+    fn part2(pipe_map: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut solver = SecondSolver::new();
+        return solve(&mut solver, pipe_map).into();
+    }
+}
+
+struct FirstSolver {
+    size: i64,
+}
+
+impl FirstSolver {
+    pub fn new() -> Self {
+        return FirstSolver { size: 0 };
+    }
+}
+
+impl Solver for FirstSolver {
+    fn init_candidate(&mut self, _i: usize, _j: usize) {
+        self.size = 0;
+    }
+
+    fn consume_step(&mut self, _i: usize, _j: usize) {
+        self.size += 1;
+    }
+
+    fn get_result(&mut self) -> i64 {
+        return (self.size + 1) >> 1;
+    }
+}
+
+struct SecondSolver {
+    double_area: i64,
+    steps: i64,
+    previous: (i64, i64),
+}
+
+impl SecondSolver {
+    pub fn new() -> Self {
+        return SecondSolver {
+            double_area: 0,
+            steps: 0,
+            previous: (-1, -1),
+        };
+    }
+}
+
+impl Solver for SecondSolver {
+    fn init_candidate(&mut self, i: usize, j: usize) {
+        self.double_area = 0;
+        self.steps = 0;
+        self.previous = (i as i64, j as i64);
+    }
+
+    /*
+    The problem reduces to counting the number of lattice points within the polygon
+    but not on the perimeter. This can be calculated using Pick's theorem:
+    i = A - b/2 + 1, where A is the area of the polygon, b is the number of integer
+    points in the boundaty and i is the number of interior lattice points.
+     */
+    fn consume_step(&mut self, i: usize, j: usize) {
+        /* Use the shoelace trapezoid formula to calculate area. Instead of computing halfs, compute
+        double the amount and divide by half at the end. */
+        let value = ((j as i64) + self.previous.1) * ((i as i64) - self.previous.0);
+        self.double_area += value;
+        self.steps += 1;
+        self.previous = (i as i64, j as i64);
+    }
+
+    fn get_result(&mut self) -> i64 {
+        /* Use absolute value to cover for clockwise/counter-clockwise traversal of the loop. */
+        return (self.double_area >> 1).abs() - (self.steps >> 1) + 1;
+    }
+}
+
+trait Solver {
+    fn init_candidate(&mut self, i: usize, j: usize);
+    fn consume_step(&mut self, i: usize, j: usize);
+    fn get_result(&mut self) -> i64;
+}
+
+/// Each tile holds the set of directions its pipe connects to. The start
+/// tile connects everywhere and ground connects nowhere.
+pub struct PipeMap {
+    map: Grid<Directions4>,
+    start_position: (usize, usize),
+}
+
+impl PipeMap {
+    pub fn read(input: &Input) -> Result<Self, ReaderError> {
+        let map = read_grid(input, |c| {
+            let connections: &[Direction4] = match c {
+                '|' => &[Direction4::South, Direction4::North],
+                '-' => &[Direction4::West, Direction4::East],
+                'L' => &[Direction4::East, Direction4::North],
+                'J' => &[Direction4::West, Direction4::North],
+                '7' => &[Direction4::West, Direction4::South],
+                'F' => &[Direction4::East, Direction4::South],
+                'S' => &Direction4::ALL,
+                '.' => &[],
+                _ => return Option::None,
+            };
+            return Option::Some(connections.iter().copied().collect());
+        })?;
+        let mut starts = map
+            .positions()
+            .filter(|&position| map[position] == Directions4::all());
+        let start_position = starts
+            .next()
+            .ok_or_else(|| ReaderError::malformed(input, 1, "no start tile"))?;
+        if let Option::Some((_, y)) = starts.next() {
+            return Result::Err(ReaderError::malformed(input, y + 1, "second start tile"));
+        }
+
+        return Result::Ok(Self {
+            map,
+            start_position,
+        });
+    }
+}
+
+/// `None` if the start tile is not on a loop.
+fn solve<S: Solver>(solver: &mut S, pipe_map: &PipeMap) -> Option<i64> {
+    let PipeMap {
+        ref map,
+        start_position,
+    } = *pipe_map;
+
+    /* Each candidate remembers the side of the tile it was entered from */
+    let mut candidates: Vec<((usize, usize), Direction4)> = Vec::with_capacity(4);
+    for direction in Direction4::ALL {
+        if let Option::Some(position) = map.step(start_position, direction) {
+            if map[position].contains(direction.reverse()) {
+                candidates.push((position, direction.reverse()));
+            }
+        }
+    }
+
+    for (mut position, mut source) in candidates {
+        solver.init_candidate(start_position.0, start_position.1);
+        while map[position] != Directions4::all() && !map[position].is_empty() {
+            solver.consume_step(position.0, position.1);
+            let exits = map[position].without(source);
+            if exits.len() != 1 || !map[position].contains(source) {
+                break;
+            }
+            let direction = exits.iter().next().unwrap();
+            match map.step(position, direction) {
+                Option::Some(next) => position = next,
+                Option::None => break,
+            };
+            source = direction.reverse();
+        }
+        if map[position] == Directions4::all() {
+            solver.consume_step(position.0, position.1);
+            return Option::Some(solver.get_result());
+        }
+    }
+
+    return Option::None;
+}
@@ -0,0 +1,97 @@
+use crate::checked::{Checked, Overflow};
+use crate::grid::Grid;
+use crate::reader::{read_grid, Input, ReaderError};
+use crate::solution::{impl_params, Answer, Solution};
+use itertools::Itertools;
+
+const SIZE_HINT: usize = 1024;
+
+pub enum Day11 {}
+
+impl Solution for Day11 {
+    type Parsed = Grid<bool>;
+    type Params = Day11Params;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_grid(input, |c| match c {
+            '.' => Option::Some(false),
+            '#' => Option::Some(true),
+            _ => Option::None,
+        });
+    }
+
+    fn part1(image: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve(image, 2).into();
+    }
+
+    fn part2(image: &Self::Parsed, params: &Self::Params) -> Answer {
+        return solve(image, params.expansion).into();
+    }
+}
+
+pub struct Day11Params {
+    /// Number of rows or columns each empty one stands for in part 2.
+    pub expansion: usize,
+}
+
+impl Default for Day11Params {
+    fn default() -> Self {
+        return Self { expansion: 1000000 };
+    }
+}
+
+impl_params!(Day11Params { expansion });
+
+fn solve(image: &Grid<bool>, empty_galaxy_size: usize) -> Checked<u64> {
+    let empty_rows = image
+        .rows()
+        .map(|row| row.iter().all(|&galaxy| !galaxy))
+        .collect_vec();
+    let empty_cols = image
+        .columns()
+        .map(|mut column| column.all(|&galaxy| !galaxy))
+        .collect_vec();
+
+    let empty_galaxy_size = empty_galaxy_size as u64;
+    let mut i: Checked<u64>;
+    let mut j: Checked<u64> = Checked::new(0);
+
+    let mut galaxies: Vec<(Checked<u64>, Checked<u64>)> = Vec::with_capacity(SIZE_HINT);
+    for real_j in 0..image.height() {
+        i = Checked::new(0);
+        for real_i in 0..image.width() {
+            if image[(real_i, real_j)] {
+                galaxies.push((i, j));
+            }
+            if empty_cols[real_i] {
+                i += empty_galaxy_size;
+            } else {
+                i += 1;
+            }
+        }
+        if empty_rows[real_j] {
+            j += empty_galaxy_size;
+        } else {
+            j += 1;
+        }
+    }
+    let galaxies = match galaxies
+        .into_iter()
+        .map(|(i, j)| Result::Ok((i.get()?, j.get()?)))
+        .collect::<Result<Vec<_>, Overflow>>()
+    {
+        Result::Ok(galaxies) => galaxies,
+        Result::Err(_) => return Checked::overflowed(),
+    };
+    let mut result = Checked::new(0);
+    for b in 1..galaxies.len() {
+        for a in 0..b {
+            let (gax, gay) = galaxies[a];
+            let (gbx, gby) = galaxies[b];
+            let distance = Checked::new(gax.abs_diff(gbx)) + gay.abs_diff(gby);
+            result += distance;
+        }
+    }
+
+    return result;
+}
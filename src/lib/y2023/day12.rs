@@ -0,0 +1,183 @@
+use crate::checked::Checked;
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+pub enum Day12 {}
+
+impl Solution for Day12 {
+    type Parsed = Vec<Record>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_lines(input)?
+            .enumerate()
+            .map(|(i, line)| parse_record(input, i + 1, &line))
+            .collect();
+    }
+
+    fn part1(records: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return FirstSolver::solve(records).into();
+    }
+
+    fn part2(records: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return SecondSolver::solve(records).into();
+    }
+}
+
+/// Conditions of the springs in a row, and sizes of the groups of broken
+/// springs. Both are non-empty.
+type Record = (Vec<Symbol>, Vec<usize>);
+
+fn parse_record(input: &Input, line: usize, text: &str) -> Result<Record, ReaderError> {
+    let (arrangement, hints) = text
+        .split_once(' ')
+        .ok_or_else(|| ReaderError::malformed(input, line, "expected `<springs> <groups>`"))?;
+    let arrangement = arrangement
+        .chars()
+        .map(|c| match c {
+            '.' => Result::Ok(Symbol::Operational),
+            '#' => Result::Ok(Symbol::Broken),
+            '?' => Result::Ok(Symbol::Unknown),
+            _ => {
+                let message = format!("unexpected character `{}`", c);
+                Result::Err(ReaderError::malformed(input, line, message))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let hints = hints
+        .split(',')
+        .map(|x| parse_at(input, line, x))
+        .collect::<Result<Vec<usize>, _>>()?;
+    if arrangement.is_empty() || hints.contains(&0) {
+        return Result::Err(ReaderError::malformed(input, line, "empty row or group"));
+    }
+    return Result::Ok((arrangement, hints));
+}
+
+enum FirstSolver {}
+
+impl Solver for FirstSolver {
+    fn unfold((arrangement, hints): &Record) -> Record {
+        return (arrangement.clone(), hints.clone());
+    }
+}
+
+enum SecondSolver {}
+
+impl Solver for SecondSolver {
+    fn unfold((arrangement, hints): &Record) -> Record {
+        let mut next_arrangement = Vec::with_capacity(5 * arrangement.len() + 4);
+        let mut next_hints = Vec::with_capacity(5 * arrangement.len());
+        for i in 0..5 {
+            next_hints.extend(hints.iter());
+            next_arrangement.extend(arrangement.iter());
+            if i < 4 {
+                next_arrangement.push(Symbol::Unknown);
+            }
+        }
+        return (next_arrangement, next_hints);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Symbol {
+    Operational,
+    Broken,
+    Unknown,
+}
+
+trait Solver {
+    fn unfold(record: &Record) -> Record;
+
+    fn solve(records: &Vec<Record>) -> Checked<usize> {
+        let mut result = Checked::new(0);
+        for record in records.iter() {
+            let (arrangement, hints) = Self::unfold(record);
+            result += Self::get_result(&arrangement, &hints);
+        }
+        return result;
+    }
+
+    fn get_result(arrangement: &Vec<Symbol>, hints: &Vec<usize>) -> Checked<usize> {
+        let max_hint_count = hints.len();
+        let max_block_size = *hints.iter().max().unwrap();
+        let mut prev_state = vec![vec![Checked::new(0); max_block_size + 1]; hints.len() + 1];
+        let mut curr_state = vec![vec![Checked::new(0); max_block_size + 1]; hints.len() + 1];
+        for (idx, symbol) in arrangement.iter().enumerate() {
+            (prev_state, curr_state) = (curr_state, prev_state);
+            Self::clear_vec(&mut curr_state);
+            if idx == 0 {
+                prev_state[0][0] = Checked::new(1);
+            }
+            Self::propagate_state(
+                hints,
+                max_hint_count,
+                max_block_size,
+                &prev_state,
+                &mut curr_state,
+                *symbol,
+            );
+        }
+        return curr_state[hints.len()][0] + curr_state[hints.len() - 1][hints[hints.len() - 1]];
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn clear_vec(data: &mut Vec<Vec<Checked<usize>>>) {
+        for j in 0..data.len() {
+            for i in 0..data[j].len() {
+                data[j][i] = Checked::new(0);
+            }
+        }
+    }
+
+    fn propagate_state(
+        hints: &Vec<usize>,
+        max_hint_count: usize,
+        max_block_size: usize,
+        prev_state: &Vec<Vec<Checked<usize>>>,
+        curr_state: &mut Vec<Vec<Checked<usize>>>,
+        symbol: Symbol,
+    ) {
+        match symbol {
+            Symbol::Operational => {
+                for i in 0..=max_hint_count {
+                    for j in 0..=max_block_size {
+                        if j > 0 {
+                            if i >= max_hint_count || hints[i] != j {
+                                continue;
+                            }
+                            curr_state[i + 1][0] += prev_state[i][j];
+                        } else {
+                            curr_state[i][0] += prev_state[i][j];
+                        }
+                    }
+                }
+            }
+            Symbol::Broken => {
+                for i in 0..=max_hint_count {
+                    for j in 0..max_block_size {
+                        curr_state[i][j + 1] += prev_state[i][j];
+                    }
+                }
+            }
+            Symbol::Unknown => {
+                Self::propagate_state(
+                    hints,
+                    max_hint_count,
+                    max_block_size,
+                    prev_state,
+                    curr_state,
+                    Symbol::Operational,
+                );
+                Self::propagate_state(
+                    hints,
+                    max_hint_count,
+                    max_block_size,
+                    prev_state,
+                    curr_state,
+                    Symbol::Broken,
+                );
+            }
+        }
+    }
+}
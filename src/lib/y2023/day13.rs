@@ -0,0 +1,91 @@
+use crate::grid::Grid;
+use crate::reader::{parse_grid, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+pub enum Day13 {}
+
+impl Solution for Day13 {
+    type Parsed = Vec<Grid<bool>>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return parse_patterns(input, &read_lines(input)?.collect());
+    }
+
+    fn part1(patterns: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve(patterns, 0).into();
+    }
+
+    fn part2(patterns: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve(patterns, 1).into();
+    }
+}
+
+/// Patterns are separated by blank lines.
+fn parse_patterns(input: &Input, lines: &Vec<String>) -> Result<Vec<Grid<bool>>, ReaderError> {
+    let mut patterns: Vec<Grid<bool>> = vec![];
+    let mut first_line = 0;
+    for (i, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            patterns.push(parse_pattern(input, first_line, &lines[first_line..i])?);
+            first_line = i + 1;
+        }
+    }
+    patterns.push(parse_pattern(input, first_line, &lines[first_line..])?);
+    return Result::Ok(patterns);
+}
+
+/// `first_line` is the index of the pattern's first line in the input.
+fn parse_pattern(
+    input: &Input,
+    first_line: usize,
+    lines: &[String],
+) -> Result<Grid<bool>, ReaderError> {
+    return parse_grid(input, first_line + 1, lines, |c| match c {
+        '#' => Option::Some(true),
+        '.' => Option::Some(false),
+        _ => Option::None,
+    });
+}
+
+fn solve(patterns: &Vec<Grid<bool>>, num_errors: i64) -> i64 {
+    let mut result: i64 = 0;
+    for pattern in patterns.iter() {
+        result += get_result(pattern, num_errors);
+    }
+    return result;
+}
+
+fn get_result(pattern: &Grid<bool>, num_errors: i64) -> i64 {
+    if let Option::Some(j) = find_mirror(pattern, num_errors) {
+        return (j as i64) * 100;
+    }
+    /* A vertical mirror is a horizontal one in the transposed pattern */
+    if let Option::Some(i) = find_mirror(&pattern.transpose(), num_errors) {
+        return i as i64;
+    }
+    return 0;
+}
+
+/// Row above which the pattern mirrors with exactly `num_errors` smudges.
+fn find_mirror(pattern: &Grid<bool>, num_errors: i64) -> Option<usize> {
+    return (1..pattern.height()).find(|&j| test_mirror(pattern, num_errors, j));
+}
+
+fn test_mirror(pattern: &Grid<bool>, num_errors: i64, j: usize) -> bool {
+    let mut curr_errors: i64 = 0;
+    let num_rows = std::cmp::min(j, pattern.height() - j);
+    for k in 0..num_rows {
+        let above = pattern.row(j - k - 1);
+        let below = pattern.row(j + k);
+        for i in 0..pattern.width() {
+            if above[i] != below[i] {
+                curr_errors += 1;
+                if curr_errors > num_errors {
+                    return false;
+                }
+            }
+        }
+    }
+    return num_errors == curr_errors;
+}
@@ -0,0 +1,191 @@
+use crate::cycle::find_cycle;
+use crate::grid::Grid;
+use crate::reader::{read_grid, Input, ReaderError};
+use crate::solution::{impl_params, Answer, Solution};
+
+const EMPTY: u8 = 0;
+const ROUND: u8 = 1;
+const CUBE: u8 = 2;
+
+pub enum Day14 {}
+
+impl Solution for Day14 {
+    type Parsed = Grid<u8>;
+    type Params = Day14Params;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_grid(input, |c| match c {
+            '.' => Option::Some(EMPTY),
+            'O' => Option::Some(ROUND),
+            '#' => Option::Some(CUBE),
+            _ => Option::None,
+        });
+    }
+
+    fn part1(map: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let solver = FirstSolver::new();
+        return solve(&solver, map).into();
+    }
+
+    fn part2(map: &Self::Parsed, params: &Self::Params) -> Answer {
+        let solver = SecondSolver::new(params.cycles);
+        return solve(&solver, map).into();
+    }
+}
+
+pub struct Day14Params {
+    /// Number of spin cycles in part 2.
+    pub cycles: usize,
+}
+
+impl Default for Day14Params {
+    fn default() -> Self {
+        return Self { cycles: 1000000000 };
+    }
+}
+
+impl_params!(Day14Params { cycles });
+
+struct FirstSolver {}
+
+impl FirstSolver {
+    pub fn new() -> Self {
+        return Self {};
+    }
+}
+
+impl Solver for FirstSolver {
+    fn transform(&self, map: &mut Grid<u8>) {
+        let height = map.height();
+        let width = map.width();
+
+        for j in 0..height {
+            for i in 0..width {
+                let mut curr = j;
+                while curr > 0 && map[(i, curr - 1)] == EMPTY && map[(i, curr)] == ROUND {
+                    map[(i, curr - 1)] = ROUND;
+                    map[(i, curr)] = EMPTY;
+                    curr -= 1;
+                }
+            }
+        }
+    }
+
+    fn calculate_load(&self, map: &Grid<u8>) -> i64 {
+        let mut result: i64 = 0;
+        for j in 0..map.height() {
+            for i in 0..map.width() {
+                if map[(i, j)] == ROUND {
+                    result += (map.height() as i64) - (j as i64);
+                }
+            }
+        }
+        return result;
+    }
+}
+
+trait Solver {
+    fn transform(&self, map: &mut Grid<u8>);
+    fn calculate_load(&self, map: &Grid<u8>) -> i64;
+}
+
+struct SecondSolver {
+    cycles: usize,
+}
+
+impl SecondSolver {
+    pub fn new(cycles: usize) -> Self {
+        return Self { cycles };
+    }
+
+    fn tilt_north(&self, map: &mut Grid<u8>, width: usize, height: usize) {
+        for j in 0..height {
+            for i in 0..width {
+                let mut curr = j;
+                while curr > 0 && map[(i, curr - 1)] == EMPTY && map[(i, curr)] == ROUND {
+                    map[(i, curr - 1)] = ROUND;
+                    map[(i, curr)] = EMPTY;
+                    curr -= 1;
+                }
+            }
+        }
+    }
+
+    fn tilt_west(&self, map: &mut Grid<u8>, width: usize, height: usize) {
+        for i in 0..width {
+            for j in 0..height {
+                let mut curr = i;
+                while curr > 0 && map[(curr - 1, j)] == EMPTY && map[(curr, j)] == ROUND {
+                    map[(curr - 1, j)] = ROUND;
+                    map[(curr, j)] = EMPTY;
+                    curr -= 1;
+                }
+            }
+        }
+    }
+
+    fn tilt_south(&self, map: &mut Grid<u8>, width: usize, height: usize) {
+        for j in (0..height).rev() {
+            for i in 0..width {
+                let mut curr = j;
+                while curr < height - 1 && map[(i, curr + 1)] == EMPTY && map[(i, curr)] == ROUND {
+                    map[(i, curr + 1)] = ROUND;
+                    map[(i, curr)] = EMPTY;
+                    curr += 1;
+                }
+            }
+        }
+    }
+
+    fn tilt_east(&self, map: &mut Grid<u8>, width: usize, height: usize) {
+        for i in (0..width).rev() {
+            for j in 0..height {
+                let mut curr = i;
+                while curr < width - 1 && map[(curr + 1, j)] == EMPTY && map[(curr, j)] == ROUND {
+                    map[(curr + 1, j)] = ROUND;
+                    map[(curr, j)] = EMPTY;
+                    curr += 1;
+                }
+            }
+        }
+    }
+
+    fn spin(&self, map: &Grid<u8>) -> Grid<u8> {
+        let height = map.height();
+        let width = map.width();
+        let mut map = map.clone();
+        self.tilt_north(&mut map, width, height);
+        self.tilt_west(&mut map, width, height);
+        self.tilt_south(&mut map, width, height);
+        self.tilt_east(&mut map, width, height);
+        return map;
+    }
+}
+
+impl Solver for SecondSolver {
+    /*
+    Find the loop of spin cycles and jump ahead using its length.
+     */
+    fn transform(&self, map: &mut Grid<u8>) {
+        let (cycle, maps) = find_cycle(map.clone(), |map| self.spin(map));
+        *map = maps[cycle.reduce(self.cycles)].clone();
+    }
+
+    fn calculate_load(&self, map: &Grid<u8>) -> i64 {
+        let mut result: i64 = 0;
+        for j in 0..map.height() {
+            for i in 0..map.width() {
+                if map[(i, j)] == ROUND {
+                    result += (map.height() as i64) - (j as i64);
+                }
+            }
+        }
+        return result;
+    }
+}
+
+fn solve<S: Solver>(solver: &S, map: &Grid<u8>) -> i64 {
+    let mut map = map.clone();
+    solver.transform(&mut map);
+    return solver.calculate_load(&map);
+}
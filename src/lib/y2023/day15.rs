@@ -0,0 +1,167 @@
+use crate::reader::{read_split, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+pub enum Day15 {}
+
+impl Solution for Day15 {
+    type Parsed = Vec<Step>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let mut line = 1;
+        let mut steps = vec![];
+        for text in read_split(input, ',')? {
+            // Newlines are ignored.
+            let step_line = line;
+            line += text.matches('\n').count();
+            steps.push(parse_step(input, step_line, text.replace('\n', ""))?);
+        }
+        return Result::Ok(steps);
+    }
+
+    fn part1(steps: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut solver = FirstSolver::new();
+        return solve(&mut solver, steps).into();
+    }
+
+    fn part2(steps: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut solver = SecondSolver::new();
+        return solve(&mut solver, steps).into();
+    }
+}
+
+struct FirstSolver {
+    total: i64,
+}
+
+impl FirstSolver {
+    pub fn new() -> Self {
+        return Self { total: 0 };
+    }
+}
+
+impl Solver for FirstSolver {
+    fn consume(&mut self, step: &Step) {
+        self.total += hash_line(&step.text);
+    }
+
+    fn get_result(&self) -> i64 {
+        return self.total;
+    }
+}
+
+fn hash_line(line: &str) -> i64 {
+    let mut hash: i64 = 0;
+    for char in line.chars() {
+        hash += char as i64;
+        hash *= 17;
+        hash %= 256;
+    }
+    return hash;
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Insert(i64),
+    Remove,
+}
+
+pub struct Step {
+    text: String,
+    label: String,
+    operation: Operation,
+}
+
+fn parse_step(input: &Input, line: usize, text: String) -> Result<Step, ReaderError> {
+    let parsed = match text.split_once(|c| c == '=' || c == '-') {
+        Option::Some((label, "")) if text.ends_with('-') => {
+            Option::Some((label, Operation::Remove))
+        }
+        Option::Some((label, focal_length)) if text[label.len()..].starts_with('=') => {
+            match focal_length.parse() {
+                Result::Ok(focal_length @ 1..=9) => {
+                    Option::Some((label, Operation::Insert(focal_length)))
+                }
+                _ => Option::None,
+            }
+        }
+        _ => Option::None,
+    };
+    return match parsed {
+        Option::Some((label, operation)) if !label.is_empty() => Result::Ok(Step {
+            label: label.to_owned(),
+            operation,
+            text,
+        }),
+        _ => {
+            let message = format!("expected `<label>-` or `<label>=<digit>`, not `{}`", text);
+            Result::Err(ReaderError::malformed(input, line, message))
+        }
+    };
+}
+
+struct SecondSolver {
+    boxes: Vec<Vec<(String, i64)>>,
+}
+
+impl SecondSolver {
+    pub fn new() -> Self {
+        return Self {
+            boxes: vec![vec![]; 256],
+        };
+    }
+}
+
+impl Solver for SecondSolver {
+    fn consume(&mut self, step: &Step) {
+        let hash = hash_line(&step.label) as usize;
+
+        let maybe_position = self.boxes[hash]
+            .iter()
+            .position(|(item_label, _)| *item_label == step.label);
+
+        match step.operation {
+            Operation::Insert(focal_length) => {
+                if let Some(position) = maybe_position {
+                    self.boxes[hash][position].1 = focal_length;
+                } else {
+                    self.boxes[hash].push((step.label.clone(), focal_length));
+                }
+            }
+            Operation::Remove => {
+                if let Some(position) = maybe_position {
+                    self.boxes[hash].remove(position);
+                }
+            }
+        }
+    }
+
+    fn get_result(&self) -> i64 {
+        return self
+            .boxes
+            .iter()
+            .enumerate()
+            .map(|(box_num, box_contents)| {
+                box_contents
+                    .iter()
+                    .enumerate()
+                    .map(|(slot_num, slot_contents)| {
+                        (box_num as i64 + 1) * (slot_num as i64 + 1) * slot_contents.1
+                    })
+                    .sum::<i64>()
+            })
+            .sum();
+    }
+}
+
+trait Solver {
+    fn consume(&mut self, step: &Step);
+    fn get_result(&self) -> i64;
+}
+
+fn solve<S: Solver>(solver: &mut S, steps: &Vec<Step>) -> i64 {
+    for step in steps.iter() {
+        solver.consume(step);
+    }
+    return solver.get_result();
+}
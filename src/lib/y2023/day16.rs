@@ -0,0 +1,153 @@
+use crate::geom::{Direction4, Directions4, Point2};
+use crate::grid::Grid;
+use crate::reader::{read_grid, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+pub enum Day16 {}
+
+impl Solution for Day16 {
+    type Parsed = Grid<Tile>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return get_map(input);
+    }
+
+    fn part1(map: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let result = calculate_num_energized_tiles(map, (Point2::new(0, 0), Direction4::East));
+        return result.into();
+    }
+
+    fn part2(map: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let height = map.height() as i64;
+        let width = map.width() as i64;
+
+        let mut seeds: Vec<(Point2<i64>, Direction4)> =
+            Vec::with_capacity((2 * width + 2 * height) as usize);
+        for i in 0..width {
+            seeds.push((Point2::new(i, 0), Direction4::South));
+            seeds.push((Point2::new(i, height - 1), Direction4::North));
+        }
+
+        for j in 0..height {
+            seeds.push((Point2::new(0, j), Direction4::East));
+            seeds.push((Point2::new(width - 1, j), Direction4::West));
+        }
+
+        let result = seeds
+            .iter()
+            .map(|&s| calculate_num_energized_tiles(map, s))
+            .max()
+            .unwrap();
+        return result.into();
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Tile {
+    Empty,
+    VerticalSplitter,
+    HorizontalSplitter,
+    LeftMirror,
+    RightMirror,
+}
+
+fn get_map(input: &Input) -> Result<Grid<Tile>, ReaderError> {
+    return read_grid(input, |c| match c {
+        '.' => Option::Some(Tile::Empty),
+        '|' => Option::Some(Tile::VerticalSplitter),
+        '-' => Option::Some(Tile::HorizontalSplitter),
+        '\\' => Option::Some(Tile::LeftMirror),
+        '/' => Option::Some(Tile::RightMirror),
+        _ => Option::None,
+    });
+}
+
+fn calculate_num_energized_tiles(map: &Grid<Tile>, seed: (Point2<i64>, Direction4)) -> i64 {
+    /* Directions each tile has already been crossed in */
+    let mut energy: Grid<Directions4> = map.map(|_| Directions4::empty());
+
+    let mut candidates: Vec<(Point2<i64>, Direction4)> = Vec::with_capacity(256);
+    candidates.push(seed);
+
+    while let Some((start, direction)) = candidates.pop() {
+        let mut position = start;
+        loop {
+            if !energy[to_index(position)].insert(direction) {
+                break;
+            }
+            if !can_treat_as_empty(map, position, direction) {
+                break;
+            }
+            let next = position.moved(direction, 1);
+            if map.get_signed(next.into()).is_none() {
+                break;
+            }
+            position = next;
+        }
+
+        match map[to_index(position)] {
+            Tile::VerticalSplitter => match direction {
+                Direction4::West | Direction4::East => {
+                    propose_candidate(&mut candidates, map, position, Direction4::North);
+                    propose_candidate(&mut candidates, map, position, Direction4::South);
+                }
+                _ => {}
+            },
+            Tile::HorizontalSplitter => match direction {
+                Direction4::North | Direction4::South => {
+                    propose_candidate(&mut candidates, map, position, Direction4::West);
+                    propose_candidate(&mut candidates, map, position, Direction4::East);
+                }
+                _ => {}
+            },
+            /* '\\' turns north into west and east into south */
+            Tile::LeftMirror => {
+                let reflected = match direction {
+                    Direction4::North | Direction4::South => direction.turn_left(),
+                    Direction4::West | Direction4::East => direction.turn_right(),
+                };
+                propose_candidate(&mut candidates, map, position, reflected);
+            }
+            /* '/' turns north into east and west into south */
+            Tile::RightMirror => {
+                let reflected = match direction {
+                    Direction4::North | Direction4::South => direction.turn_right(),
+                    Direction4::West | Direction4::East => direction.turn_left(),
+                };
+                propose_candidate(&mut candidates, map, position, reflected);
+            }
+            _ => {}
+        };
+    }
+
+    return energy.iter().filter(|tile| !tile.is_empty()).count() as i64;
+}
+
+fn can_treat_as_empty(map: &Grid<Tile>, position: Point2<i64>, direction: Direction4) -> bool {
+    return match map[to_index(position)] {
+        Tile::Empty => true,
+        Tile::VerticalSplitter => matches!(direction, Direction4::North | Direction4::South),
+        Tile::HorizontalSplitter => matches!(direction, Direction4::West | Direction4::East),
+        _ => false,
+    };
+}
+
+/// Queues the beam leaving `position` in the given direction, unless it
+/// leaves the map.
+fn propose_candidate(
+    candidates: &mut Vec<(Point2<i64>, Direction4)>,
+    map: &Grid<Tile>,
+    position: Point2<i64>,
+    direction: Direction4,
+) {
+    let next = position.moved(direction, 1);
+    if map.get_signed(next.into()).is_none() {
+        return;
+    }
+    candidates.push((next, direction));
+}
+
+fn to_index(position: Point2<i64>) -> (usize, usize) {
+    return (position.x as usize, position.y as usize);
+}
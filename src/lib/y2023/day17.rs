@@ -0,0 +1,95 @@
+use crate::geom::Direction4;
+use crate::grid::Grid;
+use crate::reader::{read_grid, Input, ReaderError};
+use crate::search::astar;
+use crate::solution::{Answer, Solution};
+
+/// Position, direction of the last move (`None` before the first one) and
+/// number of extra blocks moved in that direction.
+type State = ((usize, usize), Option<Direction4>, usize);
+
+pub enum Day17 {}
+
+impl Solution for Day17 {
+    type Parsed = Grid<usize>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_grid(input, |c| c.to_digit(10).map(|digit| digit as usize));
+    }
+
+    fn part1(grid: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<FirstSolver>(grid).into();
+    }
+
+    fn part2(grid: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<SecondSolver>(grid).into();
+    }
+}
+
+enum FirstSolver {}
+
+impl Solver for FirstSolver {
+    fn get_min_steps() -> usize {
+        return 1;
+    }
+
+    fn get_max_steps() -> usize {
+        return 3;
+    }
+}
+
+enum SecondSolver {}
+
+impl Solver for SecondSolver {
+    fn get_min_steps() -> usize {
+        return 4;
+    }
+
+    fn get_max_steps() -> usize {
+        return 10;
+    }
+}
+
+trait Solver {
+    /// Blocks the crucible must move before it can turn or stop.
+    fn get_min_steps() -> usize;
+    /// Blocks the crucible can move before it must turn.
+    fn get_max_steps() -> usize;
+}
+
+/// States reachable in one move, with the heat lost entering them.
+fn get_neighbours<S: Solver>(grid: &Grid<usize>, &(position, d, k): &State) -> Vec<(State, usize)> {
+    let min_steps = S::get_min_steps();
+    let max_steps = S::get_max_steps();
+    let mut neighbours = Vec::with_capacity(4);
+    for direction in Direction4::ALL {
+        let keeps_direction = d == Option::Some(direction);
+        if d == Option::Some(direction.reverse())
+            || (keeps_direction && k >= max_steps - 1)
+            || (!keeps_direction && d.is_some() && k < min_steps - 1)
+        {
+            continue;
+        }
+        if let Option::Some(next) = grid.step(position, direction) {
+            let nk = if keeps_direction { k + 1 } else { 0 };
+            neighbours.push(((next, Option::Some(direction), nk), grid[next]));
+        }
+    }
+    return neighbours;
+}
+
+/// `None` if the crucible cannot reach the target.
+fn solve<S: Solver>(grid: &Grid<usize>) -> Option<usize> {
+    let target = (grid.width() - 1, grid.height() - 1);
+    let min_steps = S::get_min_steps();
+
+    let path = astar(
+        [((0, 0), Option::None, 0)],
+        |state| get_neighbours::<S>(grid, state),
+        /* Every block loses at least one unit of heat */
+        |&(position, _, _)| (target.0 - position.0) + (target.1 - position.1),
+        |&(position, d, k)| position == target && d.is_some() && k >= min_steps - 1,
+    );
+    return path.map(|path| path.cost);
+}
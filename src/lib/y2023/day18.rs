@@ -0,0 +1,104 @@
+use crate::geom::{Direction4, Point2};
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+use itertools::Itertools;
+
+pub enum Day18 {}
+
+impl Solution for Day18 {
+    type Parsed = Vec<Instruction>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return read_lines(input)?
+            .enumerate()
+            .map(|(i, line)| parse_instruction(input, i + 1, &line))
+            .collect();
+    }
+
+    fn part1(instructions: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<FirstSolver>(instructions).into();
+    }
+
+    fn part2(instructions: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<SecondSolver>(instructions).into();
+    }
+}
+
+/// One line of the dig plan, read both ways.
+pub struct Instruction {
+    direction: Direction4,
+    steps: i64,
+    color_direction: Direction4,
+    color_steps: i64,
+}
+
+fn parse_instruction(input: &Input, line: usize, text: &str) -> Result<Instruction, ReaderError> {
+    let malformed =
+        || ReaderError::malformed(input, line, "expected `<direction> <steps> (#<color>)`");
+    let (direction, steps, color) = text.split(' ').collect_tuple().ok_or_else(malformed)?;
+    let direction = match direction {
+        "U" => Direction4::North,
+        "L" => Direction4::West,
+        "D" => Direction4::South,
+        "R" => Direction4::East,
+        _ => return Result::Err(malformed()),
+    };
+    let steps = parse_at::<u32>(input, line, steps)?;
+    let color = color
+        .strip_prefix("(#")
+        .and_then(|color| color.strip_suffix(')'))
+        .filter(|color| color.len() == 6 && color.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(malformed)?;
+    let color_direction = match &color[5..] {
+        "0" => Direction4::East,
+        "1" => Direction4::South,
+        "2" => Direction4::West,
+        "3" => Direction4::North,
+        _ => return Result::Err(malformed()),
+    };
+    let color_steps = i64::from_str_radix(&color[..5], 16).map_err(|_| malformed())?;
+    return Result::Ok(Instruction {
+        direction,
+        steps: steps as i64,
+        color_direction,
+        color_steps,
+    });
+}
+
+struct FirstSolver;
+
+impl Solver for FirstSolver {
+    fn get_step(instruction: &Instruction) -> (Direction4, i64) {
+        return (instruction.direction, instruction.steps);
+    }
+}
+
+struct SecondSolver;
+
+impl Solver for SecondSolver {
+    fn get_step(instruction: &Instruction) -> (Direction4, i64) {
+        return (instruction.color_direction, instruction.color_steps);
+    }
+}
+
+trait Solver {
+    fn get_step(instruction: &Instruction) -> (Direction4, i64);
+}
+
+fn solve<S: Solver>(instructions: &Vec<Instruction>) -> i64 {
+    let mut position: Point2<i64> = Point2::new(0, 0);
+    let mut double_area: i64 = 0;
+    let mut boundary: i64 = 0;
+    for instruction in instructions.iter() {
+        let (direction, steps) = S::get_step(instruction);
+        boundary += steps;
+        let next = position.moved(direction, steps);
+        /* Shoelace trapezoid formula, doubled: (y_1 + y_0) * (x_1 - x_0) */
+        double_area += (next.y + position.y) * (next.x - position.x);
+        position = next;
+    }
+
+    // Calculate result using Pick's theorem
+    return boundary + (double_area >> 1).abs() - (boundary >> 1) + 1;
+}
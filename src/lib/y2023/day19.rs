@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+
+use crate::checked::Checked;
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{impl_params, Answer, Solution};
+use itertools::Itertools;
+
+pub enum Day19 {}
+
+impl Solution for Day19 {
+    /// The workflows by name, and the ratings of every part.
+    type Parsed = (HashMap<String, Workflow>, Vec<HashMap<String, i64>>);
+    type Params = Day19Params;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let lines = read_lines(input)?.collect_vec();
+        let blank = lines.iter().position(String::is_empty).ok_or_else(|| {
+            let message = "expected a blank line after the workflows";
+            return ReaderError::malformed(input, lines.len() + 1, message);
+        })?;
+        let workflow_map = parse_workflow_map(input, &lines[..blank])?;
+        let ratings = lines[(blank + 1)..]
+            .iter()
+            .enumerate()
+            .map(|(i, line)| parse_data(input, blank + i + 2, line))
+            .collect::<Result<Vec<_>, _>>()?;
+        return Result::Ok((workflow_map, ratings));
+    }
+
+    fn part1((workflow_map, ratings): &Self::Parsed, params: &Self::Params) -> Answer {
+        return FirstSolver::get_result(workflow_map, ratings, params).into();
+    }
+
+    fn part2((workflow_map, ratings): &Self::Parsed, params: &Self::Params) -> Answer {
+        return SecondSolver::get_result(workflow_map, ratings, params).into();
+    }
+}
+
+pub struct Day19Params {
+    /// Lowest possible rating of each category in part 2.
+    pub min_rating: i64,
+    /// Highest possible rating of each category in part 2.
+    pub max_rating: i64,
+}
+
+impl Default for Day19Params {
+    fn default() -> Self {
+        return Self {
+            min_rating: 1,
+            max_rating: 4000,
+        };
+    }
+}
+
+impl_params!(Day19Params {
+    min_rating,
+    max_rating
+});
+
+enum FirstSolver {}
+
+impl FirstSolver {
+    fn execute_workflow_map(
+        data: &HashMap<String, i64>,
+        workflow_map: &HashMap<String, Workflow>,
+    ) -> bool {
+        let mut label = "in".to_owned();
+
+        loop {
+            let workflow = &workflow_map[&label];
+            match Self::execute_workflow(data, workflow) {
+                Decision::Workflow(next_label) => {
+                    label = next_label;
+                }
+                Decision::Accepted => {
+                    return true;
+                }
+                Decision::Rejected => {
+                    return false;
+                }
+            };
+        }
+    }
+
+    fn execute_workflow(data: &HashMap<String, i64>, workflow: &Workflow) -> Decision {
+        for check in workflow.checks.iter() {
+            if Self::calculate_check(data, check) {
+                return check.decision.clone();
+            }
+        }
+
+        return workflow.fallback.clone();
+    }
+
+    fn calculate_check(data: &HashMap<String, i64>, check: &Check) -> bool {
+        let var_value = data[&check.var_name];
+        return match check.operation {
+            Operation::GreaterThan => var_value > check.value,
+            Operation::LessThan => var_value < check.value,
+        };
+    }
+}
+
+impl Solver for FirstSolver {
+    fn get_result(
+        workflow_map: &HashMap<String, Workflow>,
+        ratings: &Vec<HashMap<String, i64>>,
+        _params: &Day19Params,
+    ) -> Checked<i64> {
+        let mut result = Checked::new(0);
+        for data in ratings.iter() {
+            let accepted = Self::execute_workflow_map(data, workflow_map);
+            if accepted {
+                result += data.values().copied().sum::<Checked<i64>>();
+            }
+        }
+
+        return result;
+    }
+}
+
+enum SecondSolver {}
+
+impl SecondSolver {
+    fn get_result_from_workflow(
+        workflow_map: &HashMap<String, Workflow>,
+        mut range_set: HashMap<String, (i64, i64)>,
+        key: &str,
+    ) -> Checked<i64> {
+        let workflow = &workflow_map[key];
+        let mut total = Checked::new(0);
+        for check in workflow.checks.iter() {
+            let current_range = range_set[&check.var_name];
+            match check.operation {
+                Operation::GreaterThan => {
+                    if current_range.1 <= check.value {
+                        continue;
+                    }
+                }
+                Operation::LessThan => {
+                    if current_range.0 >= check.value {
+                        continue;
+                    }
+                }
+            };
+            let mut next_range_set = range_set.clone();
+            match check.operation {
+                Operation::GreaterThan => {
+                    next_range_set.get_mut(&check.var_name).unwrap().0 = check.value + 1;
+                    range_set.get_mut(&check.var_name).unwrap().1 = check.value;
+                }
+                Operation::LessThan => {
+                    next_range_set.get_mut(&check.var_name).unwrap().1 = check.value - 1;
+                    range_set.get_mut(&check.var_name).unwrap().0 = check.value;
+                }
+            };
+            total += Self::get_result_from_decision(workflow_map, next_range_set, &check.decision);
+        }
+        total += Self::get_result_from_decision(workflow_map, range_set, &workflow.fallback);
+        return total;
+    }
+
+    fn get_result_from_decision(
+        workflow_map: &HashMap<String, Workflow>,
+        range_set: HashMap<String, (i64, i64)>,
+        decision: &Decision,
+    ) -> Checked<i64> {
+        return match decision {
+            Decision::Accepted => range_set
+                .values()
+                .map(|&(start, end)| {
+                    if end < start {
+                        return Checked::new(0);
+                    }
+                    return Checked::new(end) - start + 1;
+                })
+                .product(),
+            Decision::Rejected => Checked::new(0),
+            Decision::Workflow(label) => {
+                Self::get_result_from_workflow(workflow_map, range_set, label)
+            }
+        };
+    }
+}
+
+impl Solver for SecondSolver {
+    fn get_result(
+        workflow_map: &HashMap<String, Workflow>,
+        _ratings: &Vec<HashMap<String, i64>>,
+        params: &Day19Params,
+    ) -> Checked<i64> {
+        let range = (params.min_rating, params.max_rating);
+        let ranges: HashMap<String, (i64, i64)> = HashMap::from([
+            ("x".to_owned(), range),
+            ("m".to_owned(), range),
+            ("a".to_owned(), range),
+            ("s".to_owned(), range),
+        ]);
+        return Self::get_result_from_workflow(workflow_map, ranges, "in");
+    }
+}
+
+trait Solver {
+    fn get_result(
+        workflow_map: &HashMap<String, Workflow>,
+        ratings: &Vec<HashMap<String, i64>>,
+        params: &Day19Params,
+    ) -> Checked<i64>;
+}
+
+pub struct Workflow {
+    checks: Vec<Check>,
+    fallback: Decision,
+}
+
+enum Operation {
+    LessThan,
+    GreaterThan,
+}
+
+struct Check {
+    var_name: String,
+    operation: Operation,
+    value: i64,
+    decision: Decision,
+}
+
+#[derive(Clone)]
+enum Decision {
+    Workflow(String),
+    Accepted,
+    Rejected,
+}
+
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+/// Every workflow that is sent to must exist, starting with `in`, and no part
+/// may be sent around in a loop.
+fn parse_workflow_map(
+    input: &Input,
+    lines: &[String],
+) -> Result<HashMap<String, Workflow>, ReaderError> {
+    let mut workflow_map: HashMap<String, Workflow> = HashMap::new();
+    let mut workflow_lines: HashMap<String, usize> = HashMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        let line_number = i + 1;
+        let (workflow_name, contents) = line
+            .strip_suffix('}')
+            .and_then(|line| line.split_once('{'))
+            .filter(|(workflow_name, _)| !workflow_name.is_empty())
+            .ok_or_else(|| {
+                let message = "expected `<name>{<rules>,<fallback>}`";
+                return ReaderError::malformed(input, line_number, message);
+            })?;
+        let (checks, fallback_label) = match contents.rsplit_once(',') {
+            Option::Some((checks, fallback_label)) => {
+                (checks.split(',').collect_vec(), fallback_label)
+            }
+            Option::None => (vec![], contents),
+        };
+        let fallback = parse_decision(input, line_number, fallback_label)?;
+        let checks = checks
+            .into_iter()
+            .map(|check| parse_check(input, line_number, check))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let workflow = Workflow { checks, fallback };
+
+        if workflow_map
+            .insert(workflow_name.to_owned(), workflow)
+            .is_some()
+        {
+            let message = format!("workflow `{}` is defined twice", workflow_name);
+            return Result::Err(ReaderError::malformed(input, line_number, message));
+        }
+        workflow_lines.insert(workflow_name.to_owned(), line_number);
+    }
+
+    if !workflow_map.contains_key("in") {
+        return Result::Err(ReaderError::malformed(input, 1, "no workflow `in`"));
+    }
+    for (workflow_name, workflow) in workflow_map.iter() {
+        let decisions = workflow.checks.iter().map(|check| &check.decision);
+        for decision in decisions.chain([&workflow.fallback]) {
+            if let Decision::Workflow(label) = decision {
+                if !workflow_map.contains_key(label) {
+                    let message = format!("unknown workflow `{}`", label);
+                    let line = workflow_lines[workflow_name];
+                    return Result::Err(ReaderError::malformed(input, line, message));
+                }
+            }
+        }
+    }
+    let mut finished = HashMap::new();
+    for workflow_name in workflow_map.keys() {
+        if has_loop(&workflow_map, workflow_name, &mut finished) {
+            let message = format!("workflow `{}` is part of a loop", workflow_name);
+            let line = workflow_lines[workflow_name];
+            return Result::Err(ReaderError::malformed(input, line, message));
+        }
+    }
+    return Result::Ok(workflow_map);
+}
+
+/// Depth-first search for a loop through `key`. `finished` is `false` for the
+/// workflows being searched and `true` for those known not to loop.
+fn has_loop<'a>(
+    workflow_map: &'a HashMap<String, Workflow>,
+    key: &'a str,
+    finished: &mut HashMap<&'a str, bool>,
+) -> bool {
+    match finished.get(key) {
+        Option::Some(&done) => return !done,
+        Option::None => finished.insert(key, false),
+    };
+    let workflow = &workflow_map[key];
+    let decisions = workflow.checks.iter().map(|check| &check.decision);
+    for decision in decisions.chain([&workflow.fallback]) {
+        if let Decision::Workflow(label) = decision {
+            if has_loop(workflow_map, label, finished) {
+                return true;
+            }
+        }
+    }
+    finished.insert(key, true);
+    return false;
+}
+
+fn parse_data(input: &Input, line: usize, text: &str) -> Result<HashMap<String, i64>, ReaderError> {
+    let malformed = || {
+        let message = "expected `{x=<rating>,m=<rating>,a=<rating>,s=<rating>}`";
+        return ReaderError::malformed(input, line, message);
+    };
+    let mut data = HashMap::new();
+    let contents = text
+        .strip_prefix('{')
+        .and_then(|text| text.strip_suffix('}'))
+        .ok_or_else(malformed)?;
+    for item in contents.split(',') {
+        let (category, value) = item.split_once('=').ok_or_else(malformed)?;
+        if !CATEGORIES.contains(&category) {
+            return Result::Err(malformed());
+        }
+        let value = parse_at::<u32>(input, line, value)?;
+        data.insert(category.to_owned(), value as i64);
+    }
+    if data.len() != CATEGORIES.len() || contents.split(',').count() != CATEGORIES.len() {
+        return Result::Err(malformed());
+    }
+    return Result::Ok(data);
+}
+
+fn parse_decision(input: &Input, line: usize, label: &str) -> Result<Decision, ReaderError> {
+    return match label {
+        "" => Result::Err(ReaderError::malformed(input, line, "empty workflow name")),
+        "A" => Result::Ok(Decision::Accepted),
+        "R" => Result::Ok(Decision::Rejected),
+        _ => Result::Ok(Decision::Workflow(label.to_owned())),
+    };
+}
+
+fn parse_check(input: &Input, line: usize, text: &str) -> Result<Check, ReaderError> {
+    let malformed = || {
+        let message = format!(
+            "expected `<category><operation><value>:<target>`, not `{}`",
+            text
+        );
+        return ReaderError::malformed(input, line, message);
+    };
+    let (condition, target) = text.split_once(':').ok_or_else(malformed)?;
+    let operation_position = condition
+        .find(|c| c == '>' || c == '<')
+        .ok_or_else(malformed)?;
+    let var_name = &condition[..operation_position];
+    if !CATEGORIES.contains(&var_name) {
+        return Result::Err(malformed());
+    }
+    let operation = match &condition[operation_position..(operation_position + 1)] {
+        ">" => Operation::GreaterThan,
+        _ => Operation::LessThan,
+    };
+    let value = parse_at::<u32>(input, line, &condition[(operation_position + 1)..])?;
+    return Result::Ok(Check {
+        var_name: var_name.to_owned(),
+        operation,
+        value: value as i64,
+        decision: parse_decision(input, line, target)?,
+    });
+}
@@ -1,21 +1,30 @@
-extern crate lib;
-
-use std::{
-    collections::{HashMap, VecDeque},
-    usize,
-};
+use std::{collections::VecDeque, usize};
 
+use crate::checked::Checked;
+use crate::graph::{parse_adjacency_line, Graph};
+use crate::reader::{read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
 use itertools::Itertools;
-use lib::reader::read_lines;
 
-pub fn main() {
-    first();
-    second();
-}
+pub enum Day20 {}
+
+impl Solution for Day20 {
+    type Parsed = Machine;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return Machine::from_lines(input, read_lines(input)?);
+    }
+
+    fn part1(machine: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut solver = FirstSolver::new();
+        return solver.solve(machine).into();
+    }
 
-fn first() {
-    let mut solver = FirstSolver::new();
-    solver.solve();
+    fn part2(machine: &Self::Parsed, _params: &Self::Params) -> Answer {
+        let mut solver = SecondSolver::new();
+        return solver.solve(machine).into();
+    }
 }
 
 struct FirstSolver {
@@ -33,13 +42,13 @@ impl FirstSolver {
 }
 
 impl Solver for FirstSolver {
-    fn get_result(&mut self, mut machine: Machine) -> usize {
-        let button_index = machine.index_map["button"];
-        let broadcaster_index = machine.index_map["broadcaster"];
+    fn get_result(&mut self, mut machine: Machine) -> Option<Checked<usize>> {
+        let button_index = machine.graph.index_of("button")?;
+        let broadcaster_index = machine.graph.index_of("broadcaster")?;
         for _ in 0..1000 {
             self.run_machine(&mut machine, button_index, broadcaster_index);
         }
-        return self.num_low * self.num_high;
+        return Option::Some(Checked::new(self.num_low) * self.num_high);
     }
 
     fn on_queued_pulse(&mut self, _source: usize, _destination: usize, strength: bool) {
@@ -69,16 +78,15 @@ impl SecondSolver {
 
 impl Solver for SecondSolver {
     /// rx is bottlenecked by a conjunction module. We should focus on it and find
-    /// cycles in its input.
-    fn get_result(&mut self, mut machine: Machine) -> usize {
-        let button_index = machine.index_map["button"];
-        let broadcaster_index = machine.index_map["broadcaster"];
-        let output_module_position = machine.index_map["rx"];
+    /// cycles in its input. There is no answer for machines without rx.
+    fn get_result(&mut self, mut machine: Machine) -> Option<Checked<usize>> {
+        let button_index = machine.graph.index_of("button")?;
+        let broadcaster_index = machine.graph.index_of("broadcaster")?;
+        let output_module_position = machine.graph.index_of("rx")?;
         let bottleneck_module_position = machine
             .modules
             .iter()
-            .find_position(|module| module.destinations.contains(&output_module_position))
-            .unwrap()
+            .find_position(|module| module.destinations.contains(&output_module_position))?
             .0;
         self.cycle_lengths = machine
             .modules
@@ -91,7 +99,11 @@ impl Solver for SecondSolver {
                 }
             })
             .collect();
-        self.num_cycles = self.cycle_lengths.iter().filter(|c| **c == usize::MAX).count();
+        self.num_cycles = self
+            .cycle_lengths
+            .iter()
+            .filter(|c| **c == usize::MAX)
+            .count();
         while self.num_cycles > 0 {
             self.num_iters += 1;
             self.run_machine(&mut machine, button_index, broadcaster_index);
@@ -100,13 +112,8 @@ impl Solver for SecondSolver {
             .cycle_lengths
             .iter()
             .filter(|c| **c != 0)
-            .fold(usize::MAX, |acc, c| {
-                if acc == usize::MAX {
-                    *c
-                } else {
-                    num::integer::lcm(acc, *c)
-                }
-            });
+            .map(|&c| Checked::new(c))
+            .reduce(|acc, c| acc.lcm(c));
     }
 
     fn on_queued_pulse(&mut self, _source: usize, destination: usize, strength: bool) {
@@ -118,26 +125,21 @@ impl Solver for SecondSolver {
     }
 }
 
-fn second() {
-    let mut solver = SecondSolver::new();
-    solver.solve();
-}
-
-#[derive(Debug)]
-struct Machine {
+#[derive(Clone, Debug)]
+pub struct Machine {
+    graph: Graph<String>,
     modules: Vec<Module>,
-    index_map: HashMap<String, usize>,
     pulse_queue: VecDeque<(usize, usize, bool)>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Module {
     sources: Vec<usize>,
     destinations: Vec<usize>,
     class: ModuleClass,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum ModuleClass {
     Noop,
     Button,
@@ -146,100 +148,86 @@ enum ModuleClass {
     Conjunction(Vec<bool>),
 }
 
-trait Solver {
-    fn get_result(&mut self, machine: Machine) -> usize;
-    fn on_queued_pulse(&mut self, source: usize, destination: usize, strength: bool);
-
-    fn solve(&mut self) {
-        let mut machine = Self::get_machine();
-        Self::init_memory(&mut machine);
-        let result = self.get_result(machine);
-        println!("{}", result);
-    }
-
-    fn get_machine() -> Machine {
-        let line_collection = read_lines("data/2023/20/input.txt");
-        let mut machine = Machine {
-            modules: vec![],
-            index_map: HashMap::new(),
-            pulse_queue: VecDeque::new(),
-        };
+impl Machine {
+    /// Every module is defined at most once, and so is the broadcaster.
+    pub fn from_lines<I: Iterator<Item = String>>(
+        input: &Input,
+        line_collection: I,
+    ) -> Result<Self, ReaderError> {
+        let mut graph: Graph<String> = Graph::new_directed();
+        let mut classes: Vec<ModuleClass> = vec![];
         Self::add_machine_spec(
-            &mut machine,
-            "button".to_owned(),
+            &mut graph,
+            &mut classes,
+            "button",
             ModuleClass::Button,
-            vec!["broadcaster".to_owned()],
+            vec!["broadcaster"],
         );
-        for line in line_collection {
-            let (source, class, destinations) = Self::parse_line(line);
-            Self::add_machine_spec(&mut machine, source, class, destinations);
-        }
-        for module in machine.modules.iter_mut() {
-            module.sources.sort();
-            module.destinations.sort();
+        let mut defined = vec![true];
+        for (i, line) in line_collection.enumerate() {
+            let malformed = |message: &str| ReaderError::malformed(input, i + 1, message);
+            let (source, destinations) = parse_adjacency_line(&line)
+                .ok_or_else(|| malformed("expected `<module> -> <destinations>`"))?;
+            let (name, class) =
+                Self::parse_module(source).ok_or_else(|| malformed("unknown module type"))?;
+            let index = graph.add_node(name.to_owned());
+            defined.resize(graph.len(), false);
+            if defined[index] {
+                return Result::Err(malformed("module defined twice"));
+            }
+            defined[index] = true;
+            Self::add_machine_spec(&mut graph, &mut classes, name, class, destinations);
         }
-        return machine;
+        classes.resize(graph.len(), ModuleClass::Noop);
+
+        let modules = graph
+            .indices()
+            .zip(classes)
+            .map(|(index, class)| Module {
+                sources: graph.predecessors(index),
+                destinations: graph.neighbours(index).collect(),
+                class,
+            })
+            .collect_vec();
+        let mut machine = Machine {
+            graph,
+            modules,
+            pulse_queue: VecDeque::new(),
+        };
+        Self::init_memory(&mut machine);
+        return Result::Ok(machine);
     }
 
     fn add_machine_spec(
-        machine: &mut Machine,
-        source: String,
+        graph: &mut Graph<String>,
+        classes: &mut Vec<ModuleClass>,
+        source: &str,
         class: ModuleClass,
-        destinations: Vec<String>,
+        destinations: Vec<&str>,
     ) {
-        let source_index = Self::get_label_index(machine, source);
-        let destination_indexes = destinations
-            .into_iter()
-            .map(|label| Self::get_label_index(machine, label))
-            .collect_vec();
-        machine.modules[source_index].class = class;
-        for destination_index in destination_indexes.iter() {
-            machine.modules[*destination_index]
-                .sources
-                .push(source_index);
+        let source_index = graph.add_node(source.to_owned());
+        for destination in destinations {
+            let destination_index = graph.add_node(destination.to_owned());
+            graph.add_edge(source_index, destination_index, ());
         }
-        machine.modules[source_index].destinations = destination_indexes;
+        classes.resize(graph.len(), ModuleClass::Noop);
+        classes[source_index] = class;
     }
 
-    fn get_label_index(machine: &mut Machine, label: String) -> usize {
-        if machine.index_map.contains_key(&label) {
-            return machine.index_map[&label];
+    fn parse_module(label: &str) -> Option<(&str, ModuleClass)> {
+        if label == "broadcaster" {
+            return Option::Some((label, ModuleClass::Broadcast));
         }
 
-        let index = machine.modules.len();
-        machine.modules.push(Module {
-            class: ModuleClass::Noop,
-            sources: vec![],
-            destinations: vec![],
-        });
-        machine.index_map.insert(label, index);
-        return index;
-    }
-
-    fn parse_line(mut line: String) -> (String, ModuleClass, Vec<String>) {
-        let split_position = line.find(" -> ").unwrap();
-        let destinations = line
-            .split_off(split_position + 4)
-            .split(',')
-            .map(|s| s.trim())
-            .map(String::from)
-            .collect_vec();
-
-        let _ = line.split_off(split_position);
-        if line == "broadcaster" {
-            return (line, ModuleClass::Broadcast, destinations);
+        if let Option::Some(name) = label.strip_prefix('%') {
+            return Option::Some((name, ModuleClass::FlipFlop(false)));
         }
 
-        let name = line.split_off(1);
-        if line == "%" {
-            return (name, ModuleClass::FlipFlop(false), destinations);
+        if let Option::Some(name) = label.strip_prefix('&') {
+            return Option::Some((name, ModuleClass::Conjunction(vec![])));
         }
 
-        if line == "&" {
-            return (name, ModuleClass::Conjunction(vec![]), destinations);
-        }
-
-        unreachable!()
+        return Option::None;
     }
 
     fn init_memory(machine: &mut Machine) {
@@ -255,6 +243,15 @@ trait Solver {
             }
         }
     }
+}
+
+trait Solver {
+    fn get_result(&mut self, machine: Machine) -> Option<Checked<usize>>;
+    fn on_queued_pulse(&mut self, source: usize, destination: usize, strength: bool);
+
+    fn solve(&mut self, machine: &Machine) -> Option<Checked<usize>> {
+        return self.get_result(machine.clone());
+    }
 
     fn get_pulse_strength(
         machine: &mut Machine,
@@ -262,7 +259,7 @@ trait Solver {
         destination: usize,
         input: bool,
     ) -> Option<bool> {
-        let ref mut module = machine.modules[destination];
+        let module = &mut machine.modules[destination];
         return match module.class {
             ModuleClass::Broadcast => Option::Some(input),
             ModuleClass::FlipFlop(ref mut state) => {
@@ -0,0 +1,198 @@
+use crate::grid::Grid;
+use crate::math::poly::Polynomial;
+use crate::reader::{read_grid, Input, ReaderError};
+use crate::search::bfs::{count_exactly, grid_distances};
+use crate::solution::{impl_params, Answer, Solution};
+use num::{BigInt, BigRational, ToPrimitive};
+
+pub enum Day21 {}
+
+impl Solution for Day21 {
+    /// Garden plots map, with the start position.
+    type Parsed = (Grid<bool>, (usize, usize));
+    type Params = Day21Params;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        return get_map(input);
+    }
+
+    fn part1((map, start_position): &Self::Parsed, params: &Self::Params) -> Answer {
+        return FirstSolver::get_result(map, *start_position, params.steps);
+    }
+
+    fn part2((map, start_position): &Self::Parsed, params: &Self::Params) -> Answer {
+        return SecondSolver::get_result(map, *start_position, params.total_steps);
+    }
+}
+
+pub struct Day21Params {
+    /// Number of steps taken in part 1.
+    pub steps: usize,
+    /// Number of steps taken in part 2.
+    pub total_steps: usize,
+}
+
+impl Default for Day21Params {
+    fn default() -> Self {
+        return Self {
+            steps: 64,
+            total_steps: 26501365,
+        };
+    }
+}
+
+impl_params!(Day21Params { steps, total_steps });
+
+enum FirstSolver {}
+
+impl Solver for FirstSolver {
+    fn get_result(map: &Grid<bool>, start_position: (usize, usize), num_steps: usize) -> Answer {
+        let distances = grid_distances(map, [start_position], |&open| open);
+        return count_exactly(distances.iter().flatten().copied(), num_steps).into();
+    }
+}
+
+enum SecondSolver {}
+
+impl SecondSolver {
+    /// Degree of the growth of the reachable tiles.
+    const DEGREE: usize = 2;
+    /// One more sample than needed, to check the fit.
+    const NUM_SAMPLES: usize = Self::DEGREE + 2;
+    /// Most steps walked on the tiled map for maps that can't be extrapolated.
+    const MAX_WALKED_STEPS: usize = 1000;
+
+    fn check_assumptions(map: &Grid<bool>, start_position: (usize, usize)) -> bool {
+        // First assumption
+        if map.width() != map.height() {
+            return false;
+        }
+        let grid_size = map.width();
+
+        // Second assumption
+        if start_position != (grid_size >> 1, grid_size >> 1) {
+            return false;
+        }
+
+        return (0..grid_size).all(|p| {
+            // Third assumption
+            return map[(p, start_position.1)]
+                && map[(start_position.0, p)]
+                // Fourth assumption
+                && map[(p, 0)]
+                && map[(0, p)]
+                && map[(p, grid_size - 1)]
+                && map[(grid_size - 1, p)];
+        });
+    }
+
+    /// Distances from the start on the map repeated `radius` times around
+    /// its copy in the middle.
+    fn get_distances(
+        map: &Grid<bool>,
+        start_position: (usize, usize),
+        radius: usize,
+    ) -> Grid<Option<usize>> {
+        let num_copies = 2 * radius + 1;
+        let width = map.width();
+        let height = map.height();
+        let mut tiled_map = Grid::filled(num_copies * width, num_copies * height, false);
+        for y in 0..tiled_map.height() {
+            for x in 0..tiled_map.width() {
+                tiled_map[(x, y)] = map[(x % width, y % height)];
+            }
+        }
+        let center = (
+            radius * width + start_position.0,
+            radius * height + start_position.1,
+        );
+        return grid_distances(&tiled_map, [center], |&open| open);
+    }
+
+    /// Walks the tiled map, far enough that no path leaves it.
+    fn walk(map: &Grid<bool>, start_position: (usize, usize), num_steps: usize) -> Answer {
+        if num_steps > Self::MAX_WALKED_STEPS {
+            return Answer::Empty;
+        }
+        let radius = num_steps / map.width().min(map.height()) + 1;
+        let distances = Self::get_distances(map, start_position, radius);
+        return count_exactly(distances.iter().flatten().copied(), num_steps).into();
+    }
+}
+
+impl Solver for SecondSolver {
+    /// We make the following assumptions:
+    /// 1. The grid is square.
+    /// 2. The start position is right in the middle of the map.
+    /// 3. The horizontal/vertical path from the start position to the edge of
+    /// the map doesn't have obstacles.
+    /// 4. The edges of the map are empty.
+    /// Then the copies of the map are entered through their corners or the
+    /// middle of their edges, just as fast as if there were no obstacles, so
+    /// every `grid_size` steps the reachable area grows by a ring of copies,
+    /// and rings grow linearly. Therefore, for a fixed number of steps modulo
+    /// `grid_size`, the number of reachable tiles is a quadratic polynomial in
+    /// the number of copies walked across, which we fit to a few samples.
+    /// Other maps, such as the examples, are only walked for up to
+    /// `MAX_WALKED_STEPS` steps, and there is no answer for more steps or when
+    /// the samples don't fit.
+    fn get_result(map: &Grid<bool>, start_position: (usize, usize), num_steps: usize) -> Answer {
+        if !Self::check_assumptions(map, start_position) {
+            return Self::walk(map, start_position, num_steps);
+        }
+        let grid_size = map.width();
+
+        // Tile the map so that no sample walks off it.
+        let distances = Self::get_distances(map, start_position, Self::NUM_SAMPLES);
+
+        let num_jumps = num_steps / grid_size;
+        let remainder = num_steps % grid_size;
+        let samples = (0..Self::NUM_SAMPLES)
+            .map(|jumps| {
+                let steps = remainder + jumps * grid_size;
+                let count = count_exactly(distances.iter().flatten().copied(), steps);
+                return (jumps as i64, count as i64);
+            })
+            .collect::<Vec<_>>();
+        if num_jumps < Self::NUM_SAMPLES {
+            return samples[num_jumps].1.into();
+        }
+
+        let polynomial = match Polynomial::fit(&samples, Self::DEGREE) {
+            Option::Some(polynomial) => polynomial,
+            Option::None => return Answer::Empty,
+        };
+        let count = polynomial.evaluate(&BigRational::from_integer(BigInt::from(num_jumps)));
+        if !count.is_integer() {
+            return Answer::Empty;
+        }
+        return match count.to_integer().to_i64() {
+            Option::Some(count) => count.into(),
+            Option::None => Answer::Overflow,
+        };
+    }
+}
+
+trait Solver {
+    fn get_result(map: &Grid<bool>, start_position: (usize, usize), num_steps: usize) -> Answer;
+}
+
+fn get_map(input: &Input) -> Result<(Grid<bool>, (usize, usize)), ReaderError> {
+    let chars = read_grid(input, |c| match c {
+        '.' | 'S' | '#' => Option::Some(c),
+        _ => Option::None,
+    })?;
+    let mut starts = chars.positions().filter(|&position| chars[position] == 'S');
+    let start_position = starts
+        .next()
+        .ok_or_else(|| ReaderError::malformed(input, 1, "no start position"))?;
+    if let Option::Some((_, y)) = starts.next() {
+        return Result::Err(ReaderError::malformed(
+            input,
+            y + 1,
+            "second start position",
+        ));
+    }
+    let map = chars.map(|&c| c != '#');
+    return Result::Ok((map, start_position));
+}
@@ -0,0 +1,237 @@
+use std::collections::VecDeque;
+
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+use bit_set::BitSet;
+use itertools::Itertools;
+
+pub enum Day22 {}
+
+impl Solution for Day22 {
+    /// The settled bricks, with the bricks resting on each brick (dependants)
+    /// and the bricks each brick rests on (dependencies).
+    type Parsed = (Vec<Brick>, Vec<BitSet>, Vec<BitSet>);
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let bricks = read_lines(input)?
+            .enumerate()
+            .map(|(i, line)| Brick::from_line(input, i + 1, &line))
+            .collect::<Result<Vec<_>, _>>()?;
+        return settle(bricks)
+            .map_err(|i| ReaderError::malformed(input, i + 1, "the brick overlaps another one"));
+    }
+
+    fn part1((bricks, dependants, dependencies): &Self::Parsed, _params: &Self::Params) -> Answer {
+        return FirstSolver::get_result(bricks, dependants, dependencies).into();
+    }
+
+    fn part2((bricks, dependants, dependencies): &Self::Parsed, _params: &Self::Params) -> Answer {
+        return SecondSolver::get_result(bricks, dependants, dependencies).into();
+    }
+}
+
+enum FirstSolver {}
+
+impl Solver for FirstSolver {
+    fn get_result(
+        bricks: &Vec<Brick>,
+        dependants: &Vec<BitSet>,
+        dependencies: &Vec<BitSet>,
+    ) -> usize {
+        return (0..bricks.len())
+            .filter(|a| {
+                for b in dependants[*a].iter() {
+                    if dependencies[b]
+                        .difference(&BitSet::from_iter(vec![*a].into_iter()))
+                        .collect::<BitSet>()
+                        .is_empty()
+                    {
+                        return false;
+                    }
+                }
+                return true;
+            })
+            .count();
+    }
+}
+
+enum SecondSolver {}
+
+impl Solver for SecondSolver {
+    fn get_result(
+        bricks: &Vec<Brick>,
+        dependants: &Vec<BitSet>,
+        dependencies: &Vec<BitSet>,
+    ) -> usize {
+        return (0..bricks.len())
+            .map(|a| {
+                let mut removed_elems = BitSet::with_capacity(bricks.len());
+                let mut queue = VecDeque::new();
+                queue.push_back(a);
+                while let Option::Some(elem) = queue.pop_front() {
+                    removed_elems.insert(elem);
+                    for b in dependants[elem].iter() {
+                        if removed_elems.contains(b)
+                            || !dependencies[b]
+                                .difference(&removed_elems)
+                                .collect::<BitSet>()
+                                .is_empty()
+                        {
+                            continue;
+                        }
+                        queue.push_back(b);
+                    }
+                }
+                return removed_elems.len() - 1;
+            })
+            .sum();
+    }
+}
+
+#[derive(Debug)]
+pub struct Brick {
+    start: (usize, usize, usize),
+    end: (usize, usize, usize),
+}
+
+impl Brick {
+    pub fn from_line(input: &Input, line: usize, text: &str) -> Result<Self, ReaderError> {
+        let malformed =
+            || ReaderError::malformed(input, line, "expected `<x>,<y>,<z>~<x>,<y>,<z>`");
+        let (left, right) = text.split_once('~').ok_or_else(malformed)?;
+        let parse_end = |end: &str| {
+            let (x, y, z) = end.split(',').collect_tuple().ok_or_else(malformed)?;
+            let z = parse_at::<usize>(input, line, z)?;
+            if z == 0 {
+                return Result::Err(ReaderError::malformed(
+                    input,
+                    line,
+                    "the ground is at z = 0",
+                ));
+            }
+            return Result::Ok((
+                parse_at(input, line, x)?,
+                parse_at(input, line, y)?,
+                z - 1, // Remap height to be zero indexed
+            ));
+        };
+        return Result::Ok(Self {
+            start: parse_end(left)?,
+            end: parse_end(right)?,
+        });
+    }
+}
+
+trait Solver {
+    fn get_result(
+        bricks: &Vec<Brick>,
+        dependants: &Vec<BitSet>,
+        dependencies: &Vec<BitSet>,
+    ) -> usize;
+}
+
+/// Drops every brick as far down as it goes, and records which bricks rest on
+/// which. Fails with the index of a brick that overlaps another one.
+#[allow(clippy::needless_range_loop)]
+#[allow(clippy::type_complexity)]
+fn settle(bricks: Vec<Brick>) -> Result<(Vec<Brick>, Vec<BitSet>, Vec<BitSet>), usize> {
+    let space_width = bricks
+        .iter()
+        .map(|b| std::cmp::max(b.start.0, b.end.0))
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let space_depth = bricks
+        .iter()
+        .map(|b| std::cmp::max(b.start.1, b.end.1))
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let space_height = bricks
+        .iter()
+        .map(|b| std::cmp::max(b.start.2, b.end.2))
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+    // We don't care about the initial order of the bricks
+    let mut bricks = bricks.into_iter().enumerate().collect_vec();
+    bricks.sort_by_key(|(_, b)| std::cmp::min(b.start.2, b.end.2));
+    let mut space: Vec<Vec<Vec<usize>>> =
+        vec![vec![vec![usize::MAX; space_height]; space_depth]; space_width];
+    let mut dependants: Vec<BitSet> = vec![BitSet::new(); bricks.len()];
+    let mut dependencies: Vec<BitSet> = vec![BitSet::new(); bricks.len()];
+
+    for (idx, (input_index, brick)) in bricks.iter().enumerate() {
+        let min_x = std::cmp::min(brick.start.0, brick.end.0);
+        let max_x = std::cmp::max(brick.start.0, brick.end.0);
+        let min_y = std::cmp::min(brick.start.1, brick.end.1);
+        let max_y = std::cmp::max(brick.start.1, brick.end.1);
+        let min_z = std::cmp::min(brick.start.2, brick.end.2);
+        let max_z = std::cmp::max(brick.start.2, brick.end.2);
+        // Insert brick into space
+        for i in min_x..=max_x {
+            for j in min_y..=max_y {
+                for k in min_z..=max_z {
+                    if space[i][j][k] != usize::MAX {
+                        return Result::Err(*input_index);
+                    }
+                    space[i][j][k] = idx;
+                }
+            }
+        }
+        // Drop brick
+        let mut curr_min_z = min_z;
+        while can_drop(&space, min_x, max_x, min_y, max_y, curr_min_z) {
+            let curr_max_z = max_z - (min_z - curr_min_z);
+
+            for i in min_x..=max_x {
+                for j in min_y..=max_y {
+                    space[i][j][curr_min_z - 1] = idx;
+                    space[i][j][curr_max_z] = usize::MAX;
+                }
+            }
+
+            curr_min_z -= 1;
+        }
+        // Mark as dependant
+        if curr_min_z > 0 {
+            for i in min_x..=max_x {
+                for j in min_y..=max_y {
+                    let dependency = space[i][j][curr_min_z - 1];
+                    if dependency == usize::MAX {
+                        continue;
+                    }
+                    dependants[dependency].insert(idx);
+                    dependencies[idx].insert(dependency);
+                }
+            }
+        }
+    }
+
+    let bricks = bricks.into_iter().map(|(_, brick)| brick).collect();
+    return Result::Ok((bricks, dependants, dependencies));
+}
+
+#[allow(clippy::needless_range_loop)]
+fn can_drop(
+    space: &Vec<Vec<Vec<usize>>>,
+    min_x: usize,
+    max_x: usize,
+    min_y: usize,
+    max_y: usize,
+    min_z: usize,
+) -> bool {
+    if min_z == 0 {
+        return false;
+    }
+    for i in min_x..=max_x {
+        for j in min_y..=max_y {
+            if space[i][j][min_z - 1] != usize::MAX {
+                return false;
+            }
+        }
+    }
+    return true;
+}
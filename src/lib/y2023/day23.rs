@@ -0,0 +1,130 @@
+use crate::geom::Direction4;
+use crate::graph::maze::junction_graph;
+use crate::graph::path::longest_path;
+use crate::graph::Graph;
+use crate::grid::Grid;
+use crate::reader::{read_grid, Input, ReaderError};
+use crate::solution::{Answer, Solution};
+
+pub enum Day23 {}
+
+impl Solution for Day23 {
+    type Parsed = Grid<Tile>;
+    type Params = ();
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let grid = read_grid(input, Tile::from_char)?;
+        let (width, height) = (grid.width(), grid.height());
+        if width < 3 || grid[(1, 0)] != Tile::Path {
+            return Result::Err(ReaderError::malformed(
+                input,
+                1,
+                "expected a path at column 2",
+            ));
+        }
+        if grid[(width - 2, height - 1)] != Tile::Path {
+            let message = format!("expected a path at column {}", width - 1);
+            return Result::Err(ReaderError::malformed(input, height, message));
+        }
+        return Result::Ok(grid);
+    }
+
+    fn part1(grid: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<FirstSolver>(grid).into();
+    }
+
+    fn part2(grid: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return solve::<SecondSolver>(grid).into();
+    }
+}
+
+enum FirstSolver {}
+
+impl Solver for FirstSolver {
+    fn transform_grid(grid: &Grid<Tile>) -> Grid<Tile> {
+        return grid.clone();
+    }
+}
+
+enum SecondSolver {}
+
+impl Solver for SecondSolver {
+    fn transform_grid(grid: &Grid<Tile>) -> Grid<Tile> {
+        return grid.map(|&t| if t == Tile::Forest { t } else { Tile::Path });
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction4),
+}
+
+impl Tile {
+    pub fn from_char(char: char) -> Option<Self> {
+        return match char {
+            '.' => Option::Some(Tile::Path),
+            '#' => Option::Some(Tile::Forest),
+            '^' => Option::Some(Tile::Slope(Direction4::North)),
+            '<' => Option::Some(Tile::Slope(Direction4::West)),
+            'v' => Option::Some(Tile::Slope(Direction4::South)),
+            '>' => Option::Some(Tile::Slope(Direction4::East)),
+            _ => Option::None,
+        };
+    }
+
+    /// Slopes cannot be climbed.
+    pub fn can_enter(&self, direction: Direction4) -> bool {
+        return match self {
+            Tile::Path => true,
+            Tile::Forest => false,
+            Tile::Slope(slope_direction) => *slope_direction != direction.reverse(),
+        };
+    }
+}
+
+/// Graph between the junctions of the grid, weighted by path length.
+#[derive(Debug)]
+struct CompressedGraph {
+    source_index: usize,
+    target_index: usize,
+    graph: Graph<(usize, usize), usize>,
+}
+
+impl CompressedGraph {
+    /// `None` if the target cannot be reached.
+    pub fn get_longest_path(&self) -> Option<usize> {
+        let path = longest_path(&self.graph, self.source_index, self.target_index)?;
+        return Option::Some(path.cost as usize);
+    }
+
+    /// `None` unless both the source and the target are open.
+    pub fn from_grid(
+        grid: &Grid<Tile>,
+        source: (usize, usize),
+        target: (usize, usize),
+    ) -> Option<Self> {
+        let graph = junction_graph(grid, &[source, target], Tile::can_enter);
+        return Option::Some(Self {
+            source_index: graph.index_of(&source)?,
+            target_index: graph.index_of(&target)?,
+            graph,
+        });
+    }
+}
+
+trait Solver {
+    fn transform_grid(grid: &Grid<Tile>) -> Grid<Tile>;
+}
+
+fn solve<S: Solver>(grid: &Grid<Tile>) -> Option<usize> {
+    let grid = S::transform_grid(grid);
+
+    let source = (1, 0);
+    let target = (grid.width() - 2, grid.height() - 1);
+
+    let compressed_graph = CompressedGraph::from_grid(&grid, source, target)?;
+
+    return compressed_graph.get_longest_path();
+}
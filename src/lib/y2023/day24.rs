@@ -0,0 +1,415 @@
+use std::cell::OnceCell;
+
+use crate::checked::{Checked, Overflow};
+use crate::geom::Point2;
+use crate::linalg::solve;
+use crate::reader::{parse_at, read_lines, Input, ReaderError};
+use crate::solution::{impl_params, Answer, Part, Solution};
+use itertools::Itertools;
+use num::{BigInt, BigRational};
+
+pub enum Day24 {}
+
+impl Solution for Day24 {
+    type Parsed = Hailstones;
+    type Params = Day24Params;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let entities = read_lines(input)?
+            .enumerate()
+            .map(|(i, line)| Entity::from_line(input, i + 1, &line))
+            .collect::<Result<_, _>>()?;
+        return Result::Ok(Hailstones {
+            entities,
+            crossings: OnceCell::new(),
+        });
+    }
+
+    fn part1(hailstones: &Self::Parsed, params: &Self::Params) -> Answer {
+        return match hailstones.get_crossings(params) {
+            Result::Ok(counts) => counts.inside.into(),
+            Result::Err(Overflow) => Answer::Overflow,
+        };
+    }
+
+    fn part2(hailstones: &Self::Parsed, params: &Self::Params) -> Answer {
+        return SecondSolver::get_result(&hailstones.entities, params);
+    }
+
+    /// How every pair of paths meets, of which part 1 counts the ones inside.
+    fn details(
+        hailstones: &Self::Parsed,
+        params: &Self::Params,
+        part: Part,
+    ) -> Vec<(String, String)> {
+        if part != Part::One {
+            return Vec::new();
+        }
+        let counts = match hailstones.get_crossings(params) {
+            Result::Ok(counts) => counts,
+            Result::Err(Overflow) => return Vec::new(),
+        };
+        return [
+            ("parallel", counts.parallel),
+            ("coincident", counts.coincident),
+            ("past", counts.past),
+            ("outside", counts.outside),
+            ("inside", counts.inside),
+        ]
+        .into_iter()
+        .map(|(name, count)| (name.to_owned(), count.to_string()))
+        .collect();
+    }
+}
+
+pub struct Day24Params {
+    /// Lower bound of the test area on both axes in part 1.
+    pub area_min: i64,
+    /// Upper bound of the test area on both axes in part 1.
+    pub area_max: i64,
+}
+
+impl Default for Day24Params {
+    fn default() -> Self {
+        return Self {
+            area_min: 200000000000000,
+            area_max: 400000000000000,
+        };
+    }
+}
+
+impl_params!(Day24Params { area_min, area_max });
+
+/// The hailstones, with how their paths cross once a part has counted them.
+pub struct Hailstones {
+    entities: Vec<Entity>,
+    crossings: OnceCell<Result<CrossingCounts, Overflow>>,
+}
+
+impl Hailstones {
+    /// The parameters are the same for every part of a run, so the crossings
+    /// are only counted once.
+    pub fn get_crossings(&self, params: &Day24Params) -> Result<CrossingCounts, Overflow> {
+        return *self
+            .crossings
+            .get_or_init(|| count_crossings(&self.entities, params));
+    }
+}
+
+/// How the XY paths of two hailstones meet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Crossing {
+    /// The paths never meet.
+    Parallel,
+    /// The paths lie on the same line, so they do not cross at a single point.
+    Coincident,
+    /// The paths cross at a time in the past of at least one hailstone.
+    Past,
+    /// The paths cross in the future, outside the test area.
+    Outside,
+    /// The paths cross in the future, inside the test area.
+    Inside,
+}
+
+/// Number of pairs of hailstones of each kind of crossing. Part 1 only counts
+/// the ones inside the test area, and reports the others as details.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CrossingCounts {
+    pub parallel: usize,
+    pub coincident: usize,
+    pub past: usize,
+    pub outside: usize,
+    pub inside: usize,
+}
+
+pub fn count_crossings(
+    entities: &Vec<Entity>,
+    params: &Day24Params,
+) -> Result<CrossingCounts, Overflow> {
+    let mut counts = CrossingCounts::default();
+    for j in 1..entities.len() {
+        for i in 0..j {
+            match entities[i].get_crossing(&entities[j], params.area_min, params.area_max)? {
+                Crossing::Parallel => counts.parallel += 1,
+                Crossing::Coincident => counts.coincident += 1,
+                Crossing::Past => counts.past += 1,
+                Crossing::Outside => counts.outside += 1,
+                Crossing::Inside => counts.inside += 1,
+            }
+        }
+    }
+    return Result::Ok(counts);
+}
+
+enum SecondSolver {}
+
+impl SecondSolver {
+    /// Positions in matrix correspond to
+    /// p_r_x, p_r_y, p_r_z, v_r_x, v_r_y, v_r_z
+    fn get_system(e: &Entity) -> (Vec<Vec<i128>>, Vec<i128>) {
+        let (px, py, pz) = (e.px as i128, e.py as i128, e.pz as i128);
+        let (vx, vy, vz) = (e.vx as i128, e.vy as i128, e.vz as i128);
+        let a = vec![
+            vec![0, -vz, vy, 0, pz, -py],
+            vec![vz, 0, -vx, -pz, 0, px],
+            vec![-vy, vx, 0, py, -px, 0],
+        ];
+
+        let b = vec![py * vz - pz * vy, pz * vx - px * vz, px * vy - py * vx];
+
+        return (a, b);
+    }
+
+    /// Exact solution of the system built from hailstones i, j and k, if it
+    /// has a unique one.
+    fn solve_system(
+        entities: &Vec<Entity>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> Option<Vec<BigRational>> {
+        let (a_i, b_i) = Self::get_system(&entities[i]);
+        let mut matrix = vec![];
+        let mut vector = vec![];
+        for other in [j, k] {
+            let (a_o, b_o) = Self::get_system(&entities[other]);
+            for row in 0..3 {
+                matrix.push(
+                    (0..6)
+                        .map(|col| to_big_rational(a_i[row][col] - a_o[row][col]))
+                        .collect_vec(),
+                );
+                vector.push(to_big_rational(b_o[row]) - to_big_rational(b_i[row]));
+            }
+        }
+        return solve(&matrix, &vector).ok();
+    }
+
+    /// Checks that the rock hits every hailstone at a non-negative time.
+    fn hits_all(
+        entities: &Vec<Entity>,
+        position: &[i128; 3],
+        velocity: &[i128; 3],
+    ) -> Result<bool, Overflow> {
+        for e in entities.iter() {
+            let p = [
+                (Checked::new(position[0]) - e.px as i128).get()?,
+                (Checked::new(position[1]) - e.py as i128).get()?,
+                (Checked::new(position[2]) - e.pz as i128).get()?,
+            ];
+            let v = [
+                (Checked::new(velocity[0]) - e.vx as i128).get()?,
+                (Checked::new(velocity[1]) - e.vy as i128).get()?,
+                (Checked::new(velocity[2]) - e.vz as i128).get()?,
+            ];
+            let product = |a: i128, b: i128| (Checked::new(a) * b).get();
+            // (p_r - p_i) = -t_i * (v_r - v_i)
+            let colinear = product(p[1], v[2])? == product(p[2], v[1])?
+                && product(p[2], v[0])? == product(p[0], v[2])?
+                && product(p[0], v[1])? == product(p[1], v[0])?;
+            if !colinear {
+                return Result::Ok(false);
+            }
+            let dot =
+                Checked::new(p[0]) * v[0] + Checked::new(p[1]) * v[1] + Checked::new(p[2]) * v[2];
+            let forward = dot.get()? <= 0;
+            let moving = v != [0, 0, 0] || p == [0, 0, 0];
+            if !forward || !moving {
+                return Result::Ok(false);
+            }
+        }
+        return Result::Ok(true);
+    }
+}
+
+impl Solver for SecondSolver {
+    /// Let:
+    /// - N = number of hailstones in the dataset
+    /// - p_i = initial position of the i-th hailstone
+    /// - v_i = velocity of the i-th hailstone
+    /// - x_i(t) = position of the i-th hailstone at time t
+    /// - p_r = initial position of the rock
+    /// - v_r = velocity of the rock
+    /// - x_r(t) = position of the rock at time t
+    /// - t_i = time of impact between the rock and hailstone i
+    /// Therefore:
+    /// - x_i(t) = p_i + t * v_i
+    /// - x_r(t) = p_r + t * v_r
+    /// - x_i(t_i) = x_r(t_i)
+    /// Which implies:
+    /// (p_r - p_i) = -t_i * (v_r - v_i)
+    /// Then (p_r - p_i) and (v_r - v_i) are colinear, which imply that
+    /// (p_r - p_i) x (v_r - v_i) = 0
+    /// and if we expand out each term of the resulting vector
+    /// (p_r - p_i)_y * (v_r - v_i)_z - (p_r - p_i)_z * (v_r - v_i)_y = 0
+    /// (p_r - p_i)_z * (v_r - v_i)_x - (p_r - p_i)_x * (v_r - v_i)_z = 0
+    /// (p_r - p_i)_x * (v_r - v_i)_y - (p_r - p_i)_y * (v_r - v_i)_x = 0
+    /// =>
+    /// p_r_y * v_r_z - p_r_y * v_i_z - p_i_y * v_r_z + p_i_y * v_i_z - p_r_z * v_r_y + p_r_z * v_i_y + p_i_z * v_r_y - p_i_z * v_i_y = 0
+    /// p_r_z * v_r_x - p_r_z * v_i_x - p_i_z * v_r_x + p_i_z * v_i_x - p_r_x * v_r_z + p_r_x * v_i_z + p_i_x * v_r_z - p_i_x * v_i_z = 0
+    /// p_r_x * v_r_y - p_r_x * v_i_y - p_i_x * v_r_y + p_i_x * v_i_y - p_r_y * v_r_x + p_r_y * v_i_x + p_i_y * v_r_x - p_i_y * v_i_x = 0
+    /// =>
+    /// -p_r_y * v_i_z - p_i_y * v_r_z + p_i_y * v_i_z + p_r_z * v_i_y + p_i_z * v_r_y - p_i_z * v_i_y = -p_r_y * v_r_z + p_r_z * v_r_y
+    /// -p_r_z * v_i_x - p_i_z * v_r_x + p_i_z * v_i_x + p_r_x * v_i_z + p_i_x * v_r_z - p_i_x * v_i_z = -p_r_z * v_r_x + p_r_x * v_r_z
+    /// -p_r_x * v_i_y - p_i_x * v_r_y + p_i_x * v_i_y + p_r_y * v_i_x + p_i_y * v_r_x - p_i_y * v_i_x = -p_r_x * v_r_y + p_r_y * v_r_x
+    /// Take 3 distinct data points i. Notice that the RHS of these equations are equal for all of these. Therefore we can equate the LHS's
+    /// to obtain a linear system in 6 unknowns and 9 equations. We can make this a square system by dropping 3 equations, and use a linear
+    /// solver to obtain a solution in time. Note that there must exist a choice of 3 data points such that the system has a unique solution,
+    /// otherwise there is no solution to the problem.
+    /// We try triples of data points until one gives a unique solution, and
+    /// check that it is an integer throw that hits every hailstone. There is
+    /// no answer otherwise.
+    fn get_result(entities: &Vec<Entity>, _params: &Day24Params) -> Answer {
+        for (i, j, k) in (0..entities.len()).tuple_combinations() {
+            let x = if let Option::Some(x) = Self::solve_system(entities, i, j, k) {
+                x
+            } else {
+                continue;
+            };
+            if !x.iter().all(|value| value.is_integer()) {
+                return Answer::Empty;
+            }
+            let x = match x
+                .iter()
+                .map(|value| i128::try_from(value.to_integer()))
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Result::Ok(x) => x,
+                Result::Err(_) => return Answer::Overflow,
+            };
+            let position = [x[0], x[1], x[2]];
+            let velocity = [x[3], x[4], x[5]];
+            match Self::hits_all(entities, &position, &velocity) {
+                Result::Ok(true) => {}
+                Result::Ok(false) => return Answer::Empty,
+                Result::Err(Overflow) => return Answer::Overflow,
+            }
+            return (Checked::new(position[0]) + position[1] + position[2]).into();
+        }
+        // Every choice of three hailstones gives a singular system.
+        return Answer::Empty;
+    }
+}
+
+#[derive(Debug)]
+pub struct Entity {
+    px: i64,
+    py: i64,
+    pz: i64,
+    vx: i64,
+    vy: i64,
+    vz: i64,
+}
+
+impl Entity {
+    pub fn from_line(input: &Input, line: usize, text: &str) -> Result<Self, ReaderError> {
+        let malformed = || {
+            let message = "expected `<px>, <py>, <pz> @ <vx>, <vy>, <vz>`";
+            return ReaderError::malformed(input, line, message);
+        };
+        let (position, velocity) = text.split_once(" @ ").ok_or_else(malformed)?;
+        let parse_vector = |vector: &str| {
+            let (x, y, z) = vector
+                .split(',')
+                .map(str::trim)
+                .collect_tuple()
+                .ok_or_else(malformed)?;
+            return Result::Ok((
+                parse_at::<i64>(input, line, x)?,
+                parse_at::<i64>(input, line, y)?,
+                parse_at::<i64>(input, line, z)?,
+            ));
+        };
+        let (px, py, pz) = parse_vector(position)?;
+        let (vx, vy, vz) = parse_vector(velocity)?;
+        return Result::Ok(Self {
+            px,
+            py,
+            pz,
+            vx,
+            vy,
+            vz,
+        });
+    }
+}
+
+impl Entity {
+    fn get_xy_position(&self) -> Point2<i128> {
+        return Point2::new(self.px as i128, self.py as i128);
+    }
+
+    fn get_xy_velocity(&self) -> Point2<i128> {
+        return Point2::new(self.vx as i128, self.vy as i128);
+    }
+
+    /// x(t) = p_i + t * v_i
+    /// x(s) = p_j + s * v_j
+    /// x(t) = x(s)
+    /// =>
+    /// t * v_i - s * v_j = p_j - p_i
+    /// =>
+    /// t = ((p_j - p_i) x v_j) / (v_i x v_j)
+    /// s = ((p_j - p_i) x v_i) / (v_i x v_j)
+    /// Everything is kept as integer fractions over (v_i x v_j), in i128,
+    /// which fits the puzzle's coordinates below 2^50 and velocities below
+    /// 2^20 comfortably. Larger values may not fit, which is reported.
+    pub fn get_crossing(
+        &self,
+        other: &Entity,
+        area_min: i64,
+        area_max: i64,
+    ) -> Result<Crossing, Overflow> {
+        let position = self.get_xy_position();
+        let velocity = self.get_xy_velocity();
+        let other_velocity = other.get_xy_velocity();
+        // The difference of two i64 always fits.
+        let offset = other.get_xy_position() - position;
+
+        let mut denominator = checked_cross(&velocity, &other_velocity).get()?;
+        if denominator == 0 {
+            if checked_cross(&offset, &velocity).get()? == 0 {
+                return Result::Ok(Crossing::Coincident);
+            }
+            return Result::Ok(Crossing::Parallel);
+        }
+        let mut t = checked_cross(&offset, &other_velocity).get()?;
+        let mut s = checked_cross(&offset, &velocity).get()?;
+        if denominator < 0 {
+            denominator = (Checked::new(0) - denominator).get()?;
+            t = (Checked::new(0) - t).get()?;
+            s = (Checked::new(0) - s).get()?;
+        }
+        if t < 0 || s < 0 {
+            return Result::Ok(Crossing::Past);
+        }
+
+        // Crossing point scaled by the denominator
+        let crossing_x =
+            (Checked::new(position.x) * denominator + Checked::new(velocity.x) * t).get()?;
+        let crossing_y =
+            (Checked::new(position.y) * denominator + Checked::new(velocity.y) * t).get()?;
+        let bound_min = (Checked::new(area_min as i128) * denominator).get()?;
+        let bound_max = (Checked::new(area_max as i128) * denominator).get()?;
+        if bound_min <= crossing_x
+            && crossing_x <= bound_max
+            && bound_min <= crossing_y
+            && crossing_y <= bound_max
+        {
+            return Result::Ok(Crossing::Inside);
+        }
+        return Result::Ok(Crossing::Outside);
+    }
+}
+
+trait Solver {
+    fn get_result(entities: &Vec<Entity>, params: &Day24Params) -> Answer;
+}
+
+/// `Point2::cross`, reporting overflows.
+fn checked_cross(a: &Point2<i128>, b: &Point2<i128>) -> Checked<i128> {
+    return Checked::new(a.x) * b.y - Checked::new(a.y) * b.x;
+}
+
+fn to_big_rational(value: i128) -> BigRational {
+    return BigRational::from_integer(BigInt::from(value));
+}
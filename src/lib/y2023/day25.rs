@@ -0,0 +1,181 @@
+use std::cell::OnceCell;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::graph::cut::{karger, stoer_wagner, MinCut};
+use crate::graph::flow::{dinic, edmonds_karp, MaxFlow};
+use crate::graph::Graph;
+use crate::reader::{read_lines, Input, ReaderError};
+use crate::solution::{impl_params, Answer, Part, Solution};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Number of wires the puzzle asks to disconnect.
+const NUM_WIRES: usize = 3;
+
+pub enum Day25 {}
+
+impl Solution for Day25 {
+    type Parsed = Diagram;
+    type Params = Day25Params;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ReaderError> {
+        let graph = Graph::from_adjacency_lines(read_lines(input)?, false).map_err(|i| {
+            return ReaderError::malformed(input, i + 1, "expected `<component>: <components>`");
+        })?;
+        return Result::Ok(Diagram {
+            graph,
+            cut: OnceCell::new(),
+        });
+    }
+
+    fn part1(diagram: &Self::Parsed, params: &Self::Params) -> Answer {
+        return diagram
+            .get_cut(params)
+            .map(|cut| cut.partitions[0].len() * cut.partitions[1].len())
+            .into();
+    }
+
+    fn part2(_diagram: &Self::Parsed, _params: &Self::Params) -> Answer {
+        return Answer::Empty;
+    }
+
+    /// The wires to disconnect.
+    fn details(diagram: &Self::Parsed, params: &Self::Params, part: Part) -> Vec<(String, String)> {
+        if part != Part::One {
+            return Vec::new();
+        }
+        return match diagram.get_wires(params) {
+            Option::Some(wires) => vec![("wires".to_owned(), wires.join(", "))],
+            Option::None => Vec::new(),
+        };
+    }
+}
+
+/// The wiring diagram, with its cut once a part has looked for it.
+pub struct Diagram {
+    graph: Graph<String>,
+    cut: OnceCell<Option<MinCut>>,
+}
+
+impl Diagram {
+    /// The parameters are the same for every part of a run, so the cut is
+    /// only looked for once.
+    pub fn get_cut(&self, params: &Day25Params) -> Option<&MinCut> {
+        return self
+            .cut
+            .get_or_init(|| get_cut(&self.graph, params))
+            .as_ref();
+    }
+
+    /// The wires of the cut, e.g. `jqt/nvd`, in order.
+    pub fn get_wires(&self, params: &Day25Params) -> Option<Vec<String>> {
+        let cut = self.get_cut(params)?;
+        let wires = cut
+            .edges
+            .iter()
+            .map(|&(from, to)| {
+                let mut wire = [
+                    self.graph.label(from).as_str(),
+                    self.graph.label(to).as_str(),
+                ];
+                wire.sort();
+                return wire.join("/");
+            })
+            .sorted()
+            .collect();
+        return Option::Some(wires);
+    }
+}
+
+pub struct Day25Params {
+    /// Min-cut algorithm: `stoer-wagner`, `karger`, `edmonds-karp` or `dinic`.
+    pub algorithm: CutAlgorithm,
+    /// Seed of the random number generator used by Karger's algorithm.
+    pub seed: u64,
+    /// Maximum number of contractions tried by Karger's algorithm.
+    pub iterations: usize,
+}
+
+impl Default for Day25Params {
+    fn default() -> Self {
+        return Self {
+            algorithm: CutAlgorithm::EdmondsKarp,
+            seed: 0,
+            iterations: 10000,
+        };
+    }
+}
+
+impl_params!(Day25Params {
+    algorithm,
+    seed,
+    iterations
+});
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CutAlgorithm {
+    StoerWagner,
+    Karger,
+    EdmondsKarp,
+    Dinic,
+}
+
+impl FromStr for CutAlgorithm {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        return match value {
+            "stoer-wagner" => Result::Ok(CutAlgorithm::StoerWagner),
+            "karger" => Result::Ok(CutAlgorithm::Karger),
+            "edmonds-karp" => Result::Ok(CutAlgorithm::EdmondsKarp),
+            "dinic" => Result::Ok(CutAlgorithm::Dinic),
+            _ => Result::Err(()),
+        };
+    }
+}
+
+impl fmt::Display for CutAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            CutAlgorithm::StoerWagner => write!(f, "stoer-wagner"),
+            CutAlgorithm::Karger => write!(f, "karger"),
+            CutAlgorithm::EdmondsKarp => write!(f, "edmonds-karp"),
+            CutAlgorithm::Dinic => write!(f, "dinic"),
+        };
+    }
+}
+
+/// `None` unless the algorithm finds a cut of exactly `NUM_WIRES` wires.
+fn get_cut(graph: &Graph<String>, params: &Day25Params) -> Option<MinCut> {
+    let cut = match params.algorithm {
+        CutAlgorithm::StoerWagner => stoer_wagner(graph),
+        CutAlgorithm::Karger => {
+            let mut rng = StdRng::seed_from_u64(params.seed);
+            karger(graph, &mut rng, params.iterations, NUM_WIRES as u64)
+        }
+        CutAlgorithm::EdmondsKarp => get_flow_cut(graph, edmonds_karp),
+        CutAlgorithm::Dinic => get_flow_cut(graph, dinic),
+    };
+    return cut.filter(|cut| cut.edges.len() == NUM_WIRES);
+}
+
+/// The first node is on one side of the cut, so the flow from it to any node
+/// on the other side is exactly the number of wires, while nodes on the same
+/// side are joined by more paths than that.
+fn get_flow_cut(
+    graph: &Graph<String>,
+    max_flow: fn(&Graph<String>, usize, usize) -> MaxFlow,
+) -> Option<MinCut> {
+    let source = 0;
+    for sink in 1..graph.len() {
+        let flow = max_flow(graph, source, sink);
+        if flow.value as usize <= NUM_WIRES {
+            let side = flow.residual.reachable(source);
+            let side = side.into_iter().map(|reached| !reached).collect();
+            return Option::Some(MinCut::from_side(graph, &side));
+        }
+    }
+    return Option::None;
+}
@@ -0,0 +1,59 @@
+use crate::registry::Entry;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+const YEAR: u16 = 2023;
+
+pub fn get_entries() -> Vec<Entry> {
+    return vec![
+        Entry::new::<day01::Day01>(YEAR, 1),
+        Entry::new::<day02::Day02>(YEAR, 2),
+        Entry::new::<day03::Day03>(YEAR, 3),
+        Entry::new::<day04::Day04>(YEAR, 4),
+        Entry::new::<day05::Day05>(YEAR, 5),
+        Entry::new::<day06::Day06>(YEAR, 6),
+        Entry::new::<day07::Day07>(YEAR, 7),
+        Entry::new::<day08::Day08>(YEAR, 8),
+        Entry::new::<day09::Day09>(YEAR, 9),
+        Entry::new::<day10::Day10>(YEAR, 10),
+        Entry::new::<day11::Day11>(YEAR, 11),
+        Entry::new::<day12::Day12>(YEAR, 12),
+        Entry::new::<day13::Day13>(YEAR, 13),
+        Entry::new::<day14::Day14>(YEAR, 14),
+        Entry::new::<day15::Day15>(YEAR, 15),
+        Entry::new::<day16::Day16>(YEAR, 16),
+        Entry::new::<day17::Day17>(YEAR, 17),
+        Entry::new::<day18::Day18>(YEAR, 18),
+        Entry::new::<day19::Day19>(YEAR, 19),
+        Entry::new::<day20::Day20>(YEAR, 20),
+        Entry::new::<day21::Day21>(YEAR, 21),
+        Entry::new::<day22::Day22>(YEAR, 22),
+        Entry::new::<day23::Day23>(YEAR, 23),
+        Entry::new::<day24::Day24>(YEAR, 24),
+        Entry::new::<day25::Day25>(YEAR, 25),
+    ];
+}
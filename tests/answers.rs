@@ -0,0 +1,195 @@
+//! Regression suite: runs every registered solution against its
+//! `data/YYYY/DD/input.txt` and compares the result with the answers recorded
+//! in `data/YYYY/DD/answers.toml`. Example fixtures named `exampleN.txt` in the
+//! same directory are discovered automatically and checked against their own
+//! table, e.g.
+//!
+//! ```toml
+//! part1 = 54953
+//! part2 = 53868
+//!
+//! [examples.example1]
+//! part1 = 142
+//! ```
+//!
+//! A `params` table next to the answers overrides the solution's parameters,
+//! e.g. `[examples.example1.params]` with `steps = 6`.
+//!
+//! Days without an answers file, fixtures without a table and parts without a
+//! recorded answer are skipped.
+
+extern crate lib;
+
+use std::fs;
+use std::path::PathBuf;
+
+use lib::reader::Input;
+use lib::registry::{get_entries, Entry};
+use lib::solution::{Answer, Part};
+
+fn expected_answer(value: &toml::Value) -> Answer {
+    return match value {
+        toml::Value::Integer(value) => Answer::Integer(*value),
+        toml::Value::String(value) => Answer::Text(value.clone()),
+        _ => panic!("unsupported answer {}", value),
+    };
+}
+
+/// Example fixtures of a day, sorted by name.
+fn get_examples(directory: &str) -> Vec<(String, PathBuf)> {
+    let mut examples = fs::read_dir(directory)
+        .unwrap_or_else(|error| panic!("cannot read {}: {}", directory, error))
+        .map(|dir_entry| dir_entry.unwrap().path())
+        .filter_map(|path| {
+            let file_name = path.file_name()?.to_str()?;
+            let name = file_name.strip_prefix("example")?.strip_suffix(".txt")?;
+            return Option::Some((format!("example{}", name), path.clone()));
+        })
+        .collect::<Vec<_>>();
+    examples.sort();
+    return examples;
+}
+
+fn get_overrides(label: &str, table: &toml::Table) -> Vec<(String, String)> {
+    let params = match table.get("params") {
+        Option::Some(toml::Value::Table(params)) => params,
+        Option::Some(_) => panic!("{}: `params` must be a table", label),
+        Option::None => return Vec::new(),
+    };
+    return params
+        .iter()
+        .map(|(name, value)| {
+            let value = match value {
+                toml::Value::String(value) => value.clone(),
+                toml::Value::Integer(value) => value.to_string(),
+                toml::Value::Float(value) => value.to_string(),
+                toml::Value::Boolean(value) => value.to_string(),
+                _ => panic!("{}: unsupported value for parameter `{}`", label, name),
+            };
+            return (name.clone(), value);
+        })
+        .collect();
+}
+
+fn check_answers(label: &str, entry: &Entry, input: &Input, table: &toml::Table) {
+    let overrides = get_overrides(label, table);
+    let mut parts = Vec::new();
+    let mut expected = Vec::new();
+    for (part, key) in [(Part::One, "part1"), (Part::Two, "part2")] {
+        match table.get(key) {
+            Option::Some(value) => {
+                parts.push(part);
+                expected.push(expected_answer(value));
+            }
+            Option::None => println!("skipping {} part {}: no recorded answer", label, part),
+        }
+    }
+
+    let answers = (entry.solve)(input, &overrides, &parts)
+        .unwrap_or_else(|error| panic!("{}: {}", label, error))
+        .answers;
+    for i in 0..parts.len() {
+        assert_eq!(answers[i], expected[i], "{} part {}", label, parts[i]);
+    }
+}
+
+fn check(year: u16, day: u8) {
+    let label = format!("{}/{:02}", year, day);
+    let directory = format!("data/{}/{:02}", year, day);
+    let answers_path = format!("{}/answers.toml", directory);
+    let contents = match fs::read_to_string(&answers_path) {
+        Result::Ok(contents) => contents,
+        Result::Err(_) => {
+            println!("skipping {}: no recorded answers", label);
+            return;
+        }
+    };
+    let table: toml::Table = contents
+        .parse()
+        .unwrap_or_else(|error| panic!("invalid {}: {}", answers_path, error));
+
+    let entry = get_entries()
+        .into_iter()
+        .find(|entry| entry.year == year && entry.day == day)
+        .unwrap_or_else(|| panic!("no solution registered for {}", label));
+
+    check_answers(&label, &entry, &entry.default_input(), &table);
+
+    let empty_table = toml::Table::new();
+    let example_tables = match table.get("examples") {
+        Option::Some(toml::Value::Table(example_tables)) => example_tables,
+        Option::Some(_) => panic!("invalid {}: `examples` must be a table", answers_path),
+        Option::None => &empty_table,
+    };
+    let examples = get_examples(&directory);
+    for (name, path) in examples.iter() {
+        let example_label = format!("{} {}", label, name);
+        match example_tables.get(name) {
+            Option::Some(toml::Value::Table(example_table)) => {
+                check_answers(
+                    &example_label,
+                    &entry,
+                    &Input::File(path.clone()),
+                    example_table,
+                );
+            }
+            Option::Some(_) => panic!(
+                "invalid {}: `examples.{}` must be a table",
+                answers_path, name
+            ),
+            Option::None => println!("skipping {}: no recorded answers", example_label),
+        }
+    }
+    for name in example_tables.keys() {
+        assert!(
+            examples
+                .iter()
+                .any(|(example_name, _)| example_name == name),
+            "{} records answers for {} but {}/{}.txt does not exist",
+            answers_path,
+            name,
+            directory,
+            name
+        );
+    }
+}
+
+macro_rules! answer_tests {
+    ($year:literal, $($name:ident => $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check($year, $day);
+            }
+        )*
+    };
+}
+
+answer_tests!(
+    2023,
+    y2023_day01 => 1,
+    y2023_day02 => 2,
+    y2023_day03 => 3,
+    y2023_day04 => 4,
+    y2023_day05 => 5,
+    y2023_day06 => 6,
+    y2023_day07 => 7,
+    y2023_day08 => 8,
+    y2023_day09 => 9,
+    y2023_day10 => 10,
+    y2023_day11 => 11,
+    y2023_day12 => 12,
+    y2023_day13 => 13,
+    y2023_day14 => 14,
+    y2023_day15 => 15,
+    y2023_day16 => 16,
+    y2023_day17 => 17,
+    y2023_day18 => 18,
+    y2023_day19 => 19,
+    y2023_day20 => 20,
+    y2023_day21 => 21,
+    y2023_day22 => 22,
+    y2023_day23 => 23,
+    y2023_day24 => 24,
+    y2023_day25 => 25,
+);
@@ -0,0 +1,88 @@
+extern crate lib;
+
+use lib::checked::{Checked, Overflow};
+use lib::reader::Input;
+use lib::solution::{solve, Answer, Part, SolveError};
+use lib::y2023::day11::Day11;
+use lib::y2023::day19::Day19;
+
+#[test]
+fn computes_exact_results() {
+    let value = Checked::new(6u64) * 7 + 8 - 10;
+    assert_eq!(value.get(), Result::Ok(40));
+    assert_eq!(
+        [1u8, 2, 3].into_iter().sum::<Checked<u8>>().get(),
+        Result::Ok(6)
+    );
+    assert_eq!(
+        [4u8, 5, 6].into_iter().product::<Checked<u8>>().get(),
+        Result::Ok(120)
+    );
+    assert_eq!(
+        Checked::new(4u64).lcm(Checked::new(6)).get(),
+        Result::Ok(12)
+    );
+}
+
+#[test]
+fn reports_overflow() {
+    assert_eq!((Checked::new(u64::MAX) + 1).get(), Result::Err(Overflow));
+    assert_eq!((Checked::new(0u64) - 1).get(), Result::Err(Overflow));
+    assert_eq!(
+        [16u8, 16].into_iter().product::<Checked<u8>>().get(),
+        Result::Err(Overflow)
+    );
+    assert_eq!(
+        Checked::new(u64::MAX).lcm(Checked::new(2)).get(),
+        Result::Err(Overflow)
+    );
+
+    // Overflow is not undone by later operations.
+    let mut value = Checked::new(u8::MAX);
+    value += 1;
+    assert_eq!((value - 200).get(), Result::Err(Overflow));
+}
+
+#[test]
+fn converts_to_answers() {
+    assert_eq!(Answer::from(Checked::new(42usize)), Answer::Integer(42));
+    assert_eq!(Answer::from(Checked::new(u64::MAX)), Answer::Overflow);
+    assert_eq!(Answer::from(Checked::<i64>::overflowed()), Answer::Overflow);
+    assert_eq!(Answer::from(42usize), Answer::Integer(42));
+    assert_eq!(Answer::from(usize::MAX), Answer::Overflow);
+}
+
+#[test]
+fn fails_on_overflowing_answers() {
+    let input = Input::File("data/2023/19/example1.txt".into());
+    let overrides = [("max_rating".to_owned(), "1000000".to_owned())];
+    let run = solve::<Day19>(&input, &overrides, &[Part::One]).unwrap();
+    assert_eq!(run.answers, vec![Answer::Integer(19114)]);
+    let result = solve::<Day19>(&input, &overrides, &[Part::Two]);
+    assert!(matches!(
+        result,
+        Result::Err(SolveError::Overflow(Part::Two))
+    ));
+}
+
+#[test]
+fn fails_on_overflowing_expansion() {
+    let input = Input::File("data/2023/11/example1.txt".into());
+    let overrides = [("expansion".to_owned(), "10000000000000000000".to_owned())];
+    let result = solve::<Day11>(&input, &overrides, &[Part::Two]);
+    assert!(matches!(
+        result,
+        Result::Err(SolveError::Overflow(Part::Two))
+    ));
+}
+
+#[test]
+fn fails_on_overflowing_rating_ranges() {
+    let input = Input::File("data/2023/19/example1.txt".into());
+    let overrides = [("min_rating".to_owned(), i64::MIN.to_string())];
+    let result = solve::<Day19>(&input, &overrides, &[Part::Two]);
+    assert!(matches!(
+        result,
+        Result::Err(SolveError::Overflow(Part::Two))
+    ));
+}
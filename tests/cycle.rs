@@ -0,0 +1,68 @@
+extern crate lib;
+
+use std::hash::{Hash, Hasher};
+
+use lib::cycle::{brent, find_cycle, floyd, Cycle};
+
+fn next(x: &u64) -> u64 {
+    return (x * x + 1) % 255;
+}
+
+#[test]
+fn detects_cycles() {
+    // 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+    let expected = Cycle {
+        prefix: 2,
+        period: 6,
+    };
+    assert_eq!(floyd(&3, next), expected);
+    assert_eq!(brent(&3, next), expected);
+    let (cycle, states) = find_cycle(3, next);
+    assert_eq!(cycle, expected);
+    assert_eq!(states, vec![3, 10, 101, 2, 5, 26, 167, 95]);
+
+    // A sequence that loops right away has no prefix.
+    let expected = Cycle {
+        prefix: 0,
+        period: 1,
+    };
+    assert_eq!(floyd(&0, |x| *x), expected);
+    assert_eq!(brent(&0, |x| *x), expected);
+    assert_eq!(find_cycle(0, |x| *x).0, expected);
+}
+
+#[test]
+fn jumps_to_step() {
+    let (cycle, states) = find_cycle(3, next);
+    assert_eq!(cycle.reduce(1), 1);
+    assert_eq!(cycle.reduce(8), 2);
+    assert_eq!(cycle.reduce(1000000000), 4);
+    let mut state = 3;
+    for _ in 0..1000 {
+        state = next(&state);
+    }
+    assert_eq!(cycle.state_at(3, next, 1000), state);
+    assert_eq!(states[cycle.reduce(1000)], state);
+}
+
+/// State whose hashes always collide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Colliding(u64);
+
+impl Hash for Colliding {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        0.hash(state);
+    }
+}
+
+#[test]
+fn confirms_hash_matches() {
+    let (cycle, _) = find_cycle(Colliding(3), |x| Colliding(next(&x.0)));
+    assert_eq!(
+        cycle,
+        Cycle {
+            prefix: 2,
+            period: 6
+        }
+    );
+}
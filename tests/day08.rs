@@ -0,0 +1,68 @@
+extern crate lib;
+
+use lib::reader::Input;
+use lib::solution::{solve, Answer, Part};
+use lib::y2023::day08::Day08;
+
+/// Ghost `k` walks from `gkA` into a loop of `lengths[k]` nodes, of which the
+/// ones at the positions in `stops[k]` are stop nodes.
+fn get_network(lengths: &[usize], stops: &[Vec<usize>]) -> String {
+    let get_label = |ghost: usize, node: usize| {
+        let suffix = if stops[ghost].contains(&node) {
+            "Z"
+        } else {
+            ""
+        };
+        return format!("g{}n{}{}", ghost, node, suffix);
+    };
+    let mut lines = vec!["L".to_owned(), String::new()];
+    for (ghost, &length) in lengths.iter().enumerate() {
+        let first = get_label(ghost, 0);
+        lines.push(format!("g{}A = ({}, {})", ghost, first, first));
+        for node in 0..length {
+            let next = get_label(ghost, (node + 1) % length);
+            lines.push(format!("{} = ({}, {})", get_label(ghost, node), next, next));
+        }
+    }
+    return lines.join("\n");
+}
+
+/// First time after the start at which every ghost is on a stop node.
+fn count_naively(lengths: &[usize], stops: &[Vec<usize>]) -> i64 {
+    let mut time = 1;
+    loop {
+        let all_stopped = (0..lengths.len()).all(|ghost| {
+            return stops[ghost].contains(&((time - 1) % lengths[ghost]));
+        });
+        if all_stopped {
+            return time as i64;
+        }
+        time += 1;
+    }
+}
+
+#[test]
+fn merges_ghosts_with_several_stops() {
+    let lengths = (2..14).collect::<Vec<_>>();
+    let stops = lengths
+        .iter()
+        .map(|&length| vec![length / 2, length - 1])
+        .collect::<Vec<_>>();
+    let input = Input::Text(get_network(&lengths, &stops));
+    let run = solve::<Day08>(&input, &[], &[Part::One, Part::Two]).unwrap();
+    assert_eq!(
+        run.answers,
+        vec![
+            Answer::Empty,
+            Answer::Integer(count_naively(&lengths, &stops))
+        ]
+    );
+}
+
+#[test]
+fn reports_ghosts_that_never_meet() {
+    // The first ghost stops at odd times and the second one at even times.
+    let input = Input::Text(get_network(&[2, 2], &[vec![0], vec![1]]));
+    let run = solve::<Day08>(&input, &[], &[Part::Two]).unwrap();
+    assert_eq!(run.answers, vec![Answer::Empty]);
+}
@@ -0,0 +1,45 @@
+extern crate lib;
+
+use lib::reader::Input;
+use lib::solution::{solve, Answer, ParamError, Part, SolveError};
+use lib::y2023::day21::Day21;
+
+#[test]
+fn walks_maps_without_open_start_lines() {
+    let input = Input::File("data/2023/21/example1.txt".into());
+    for (total_steps, count) in [(6, 16), (10, 50), (50, 1594), (100, 6536)] {
+        let overrides = [("total_steps".to_owned(), total_steps.to_string())];
+        let run = solve::<Day21>(&input, &overrides, &[Part::Two]).unwrap();
+        assert_eq!(run.answers, vec![Answer::Integer(count)]);
+    }
+    let overrides = [("steps".to_owned(), "6".to_owned())];
+    let run = solve::<Day21>(&input, &overrides, &[Part::One, Part::Two]).unwrap();
+    assert_eq!(run.answers, vec![Answer::Integer(16), Answer::Empty]);
+}
+
+#[test]
+fn extrapolates_open_maps() {
+    // Without obstacles, the tiles reachable in exactly `n` steps form a
+    // diamond of `(n + 1)^2` tiles.
+    let input = Input::Text(".....\n.....\n..S..\n.....\n.....\n".to_owned());
+    let overrides = [("total_steps".to_owned(), "1000".to_owned())];
+    let run = solve::<Day21>(&input, &overrides, &[Part::Two]).unwrap();
+    assert_eq!(run.answers, vec![Answer::Integer(1001 * 1001)]);
+}
+
+#[test]
+fn reports_unknown_params() {
+    let input = Input::File("data/2023/21/example1.txt".into());
+    let overrides = [("total".to_owned(), "6".to_owned())];
+    let result = solve::<Day21>(&input, &overrides, &[Part::Two]);
+    assert!(matches!(
+        result,
+        Result::Err(SolveError::Param(ParamError::Unknown { .. }))
+    ));
+    let overrides = [("steps".to_owned(), "six".to_owned())];
+    let result = solve::<Day21>(&input, &overrides, &[Part::One]);
+    assert!(matches!(
+        result,
+        Result::Err(SolveError::Param(ParamError::Invalid { .. }))
+    ));
+}
@@ -0,0 +1,108 @@
+extern crate lib;
+
+use lib::reader::Input;
+use lib::solution::{solve, Part, SolveError};
+use lib::y2023::day24::{count_crossings, Crossing, CrossingCounts, Day24, Day24Params, Entity};
+
+fn get_entities(lines: &[&str]) -> Vec<Entity> {
+    let input = Input::Text(lines.join("\n"));
+    return lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| Entity::from_line(&input, i + 1, line).unwrap())
+        .collect();
+}
+
+#[test]
+fn counts_crossings_exactly() {
+    let entities = get_entities(&[
+        "19, 13, 30 @ -2, 1, -2",
+        "18, 19, 22 @ -1, -1, -2",
+        "20, 25, 34 @ -2, -2, -4",
+        "12, 31, 28 @ -1, -2, -1",
+        "20, 19, 15 @ 1, -5, -3",
+    ]);
+    let params = Day24Params {
+        area_min: 7,
+        area_max: 27,
+    };
+    assert_eq!(
+        count_crossings(&entities, &params),
+        Result::Ok(CrossingCounts {
+            parallel: 1,
+            coincident: 0,
+            past: 4,
+            outside: 3,
+            inside: 2,
+        })
+    );
+}
+
+#[test]
+fn classifies_boundary_crossings() {
+    // Paths crossing at (300000000000000, 300000000000000) at t = s = 1, right
+    // on the corner of the test area.
+    let entities = get_entities(&[
+        "299999999999999, 299999999999999, 0 @ 1, 1, 0",
+        "300000000000001, 299999999999999, 0 @ -1, 1, 0",
+        "300000000000002, 300000000000002, 0 @ 2, 2, 0",
+    ]);
+    let (min, max) = (200000000000000, 300000000000000);
+    assert_eq!(
+        entities[0].get_crossing(&entities[1], min, max),
+        Result::Ok(Crossing::Inside)
+    );
+    assert_eq!(
+        entities[0].get_crossing(&entities[1], min, max - 1),
+        Result::Ok(Crossing::Outside)
+    );
+    assert_eq!(
+        entities[1].get_crossing(&entities[2], min, max),
+        Result::Ok(Crossing::Past)
+    );
+    assert_eq!(
+        entities[0].get_crossing(&entities[2], min, max),
+        Result::Ok(Crossing::Coincident)
+    );
+}
+
+#[test]
+fn reports_crossings_as_details() {
+    let input = Input::File("data/2023/24/example1.txt".into());
+    let overrides = [
+        ("area_min".to_owned(), "7".to_owned()),
+        ("area_max".to_owned(), "27".to_owned()),
+    ];
+    let run = solve::<Day24>(&input, &overrides, &[Part::One, Part::Two]).unwrap();
+    let details = run.details[0]
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        details,
+        vec![
+            "parallel=1",
+            "coincident=0",
+            "past=4",
+            "outside=3",
+            "inside=2"
+        ]
+    );
+    assert!(run.details[1].is_empty());
+}
+
+#[test]
+fn reports_overflowing_crossings() {
+    let input = Input::Text(
+        [
+            "9000000000000000000, 9000000000000000000, 0 @ -4000000000000000000, -1, 1",
+            "0, 0, 0 @ -1, 4000000000000000000, 1",
+        ]
+        .join("\n"),
+    );
+    let result = solve::<Day24>(&input, &[], &[Part::One]);
+    assert!(matches!(
+        result,
+        Result::Err(SolveError::Overflow(Part::One))
+    ));
+}
@@ -0,0 +1,24 @@
+extern crate lib;
+
+use lib::reader::Input;
+use lib::solution::{solve, Answer, Part};
+use lib::y2023::day25::Day25;
+
+#[test]
+fn reports_wires_as_details() {
+    let input = Input::File("data/2023/25/example1.txt".into());
+    for algorithm in ["stoer-wagner", "karger", "edmonds-karp", "dinic"] {
+        let overrides = [("algorithm".to_owned(), algorithm.to_owned())];
+        let run = solve::<Day25>(&input, &overrides, &[Part::One, Part::Two]).unwrap();
+        assert_eq!(run.answers, vec![Answer::Integer(54), Answer::Empty]);
+        assert_eq!(
+            run.details,
+            vec![
+                vec![("wires".to_owned(), "bvb/cmg, hfx/pzl, jqt/nvd".to_owned())],
+                vec![]
+            ],
+            "{}",
+            algorithm
+        );
+    }
+}
@@ -0,0 +1,58 @@
+extern crate lib;
+
+use lib::geom::{Direction4, Direction8, Directions4, Point2, Point3};
+
+#[test]
+fn turns_directions() {
+    for direction in Direction4::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_left().turn_left(), direction.reverse());
+    }
+    assert_eq!(Direction4::North.turn_right(), Direction4::East);
+    for direction in Direction8::ALL {
+        assert_eq!(direction.reverse().reverse(), direction);
+        assert_eq!(direction.turn_right().turn_left(), direction);
+    }
+    assert_eq!(Direction8::North.turn_right(), Direction8::NorthEast);
+    for direction in Direction4::ALL {
+        let direction8 = Direction8::from(direction);
+        assert_eq!(direction8.index(), 2 * direction.index());
+        assert_eq!(
+            Direction8::from(direction.turn_left()),
+            direction8.turn_left().turn_left()
+        );
+        assert_eq!(direction8.offset::<i64>(), direction.offset());
+    }
+}
+
+#[test]
+fn moves_points() {
+    let origin: Point2<i64> = Point2::new(0, 0);
+    assert_eq!(origin.moved(Direction4::North, 3), Point2::new(0, -3));
+    assert_eq!(origin + Direction8::SouthWest.offset(), Point2::new(-1, 1));
+    assert_eq!(Point2::new(3, -4).manhattan(&origin), 7);
+    let east: Point2<i64> = Direction4::East.offset();
+    assert!(east.cross(&Direction4::South.offset()) > 0);
+    assert_eq!(
+        Point3::new(1, 2, 3) * 2 - Point3::new(1, 1, 1),
+        Point3::new(1, 3, 5)
+    );
+
+    let corner: Point2<usize> = Point2::new(0, 0);
+    assert_eq!(corner.step(Direction4::West), Option::None);
+    assert_eq!(
+        corner.step(Direction4::South),
+        Option::Some(Point2::new(0, 1))
+    );
+}
+
+#[test]
+fn packs_direction_sets() {
+    let mut set: Directions4 = [Direction4::North, Direction4::East].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(Direction4::East));
+    assert!(!set.insert(Direction4::North));
+    assert!(set.remove(Direction4::North));
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![Direction4::East]);
+    assert_eq!(Directions4::from_bits(set.bits()), set);
+}
@@ -0,0 +1,254 @@
+extern crate lib;
+
+use lib::geom::Direction4;
+use lib::graph::cut::{karger, stoer_wagner};
+use lib::graph::flow::{dinic, edmonds_karp};
+use lib::graph::maze::junction_graph;
+use lib::graph::path::longest_path;
+use lib::graph::{parse_adjacency_line, Graph, Interner};
+use lib::grid::Grid;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn interns_labels() {
+    let mut interner: Interner<String> = Interner::new();
+    assert_eq!(interner.intern("a".to_owned()), 0);
+    assert_eq!(interner.intern("b".to_owned()), 1);
+    assert_eq!(interner.intern("a".to_owned()), 0);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.get("b"), Option::Some(1));
+    assert_eq!(interner.get("c"), Option::None);
+    assert_eq!(interner.label(1), "b");
+}
+
+#[test]
+fn parses_adjacency_lines() {
+    assert_eq!(
+        parse_adjacency_line("%a -> b, c"),
+        Option::Some(("%a", vec!["b", "c"]))
+    );
+    assert_eq!(
+        parse_adjacency_line("jqt: rhn xhk"),
+        Option::Some(("jqt", vec!["rhn", "xhk"]))
+    );
+    assert_eq!(parse_adjacency_line("jqt rhn xhk"), Option::None);
+    assert_eq!(parse_adjacency_line(": rhn"), Option::None);
+
+    let directed = Graph::from_adjacency_lines(["a -> b, c", "b -> c"], true).unwrap();
+    let c = directed.index_of("c").unwrap();
+    assert_eq!(directed.len(), 3);
+    assert_eq!(directed.edge_count(), 3);
+    assert_eq!(directed.neighbours(c).count(), 0);
+    assert_eq!(directed.predecessors(c), vec![0, 1]);
+
+    let undirected = Graph::from_adjacency_lines(["a: b c", "b: c"], false).unwrap();
+    let a = undirected.index_of("a").unwrap();
+    assert_eq!(undirected.edge_count(), 3);
+    assert_eq!(undirected.neighbours(a).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(undirected.predecessors(a), vec![1, 2]);
+}
+
+#[test]
+fn stores_weighted_edges() {
+    let mut graph: Graph<(usize, usize), usize> = Graph::new_undirected();
+    graph.add_labelled_edge((0, 0), (0, 5), 5);
+    graph.add_labelled_edge((0, 5), (0, 5), 0);
+    let end = graph.index_of(&(0, 5)).unwrap();
+    assert_eq!(graph.edges(end), &[(0, 5), (1, 0)]);
+    assert_eq!(graph.edge_count(), 2);
+    assert!(!graph.is_directed());
+}
+
+/// Two triangles joined by the single edge c-d.
+fn get_bridged_triangles() -> Graph<String> {
+    return Graph::from_adjacency_lines(["a: b c", "b: c", "c: d", "d: e f", "e: f"], false)
+        .unwrap();
+}
+
+#[test]
+fn finds_min_cut_deterministically() {
+    let graph = get_bridged_triangles();
+    let cut = stoer_wagner(&graph).unwrap();
+    let c = graph.index_of("c").unwrap();
+    let d = graph.index_of("d").unwrap();
+    assert_eq!(cut.weight, 1);
+    assert_eq!(cut.edges.len(), 1);
+    let (from, to) = cut.edges[0];
+    assert!((from, to) == (c, d) || (from, to) == (d, c));
+    assert!(cut.partitions[0].contains(&from));
+    assert!(cut.partitions[1].contains(&to));
+    assert_eq!(cut.partitions[0].len(), 3);
+    assert_eq!(cut.partitions[1].len(), 3);
+
+    // Example from the Stoer-Wagner paper, whose minimum cut weighs 4.
+    let mut weighted: Graph<usize, u64> = Graph::new_undirected();
+    for (from, to, weight) in [
+        (1, 2, 2),
+        (1, 5, 3),
+        (2, 3, 3),
+        (2, 5, 2),
+        (2, 6, 2),
+        (3, 4, 4),
+        (3, 7, 2),
+        (4, 7, 2),
+        (4, 8, 2),
+        (5, 6, 3),
+        (6, 7, 1),
+        (7, 8, 3),
+    ] {
+        weighted.add_labelled_edge(from, to, weight);
+    }
+    let cut = stoer_wagner(&weighted).unwrap();
+    assert_eq!(cut.weight, 4);
+    let mut side = cut.partitions[0]
+        .iter()
+        .map(|&i| *weighted.label(i))
+        .collect::<Vec<_>>();
+    side.sort();
+    assert!(side == vec![1, 2, 5, 6] || side == vec![3, 4, 7, 8]);
+
+    let single = Graph::<String>::from_adjacency_lines(["a: a"], false).unwrap();
+    assert_eq!(stoer_wagner(&single), Option::None);
+}
+
+#[test]
+fn finds_min_cut_with_seeded_karger() {
+    let graph = get_bridged_triangles();
+    let mut rng = StdRng::seed_from_u64(7);
+    let cut = karger(&graph, &mut rng, 100, 1).unwrap();
+    assert_eq!(cut.weight, 1);
+    assert_eq!(cut.edges.len(), 1);
+
+    let mut first = StdRng::seed_from_u64(3);
+    let mut second = StdRng::seed_from_u64(3);
+    assert_eq!(
+        karger(&graph, &mut first, 5, 0),
+        karger(&graph, &mut second, 5, 0)
+    );
+    assert_eq!(karger(&graph, &mut first, 0, 0), Option::None);
+
+    // A single contraction of a - b - c keeps the light edge with probability
+    // 100 / 101, against 1 / 2 if weights were ignored.
+    let mut weighted: Graph<&str, u64> = Graph::new_undirected();
+    weighted.add_labelled_edge("a", "b", 100);
+    weighted.add_labelled_edge("b", "c", 1);
+    let found = (0..100)
+        .filter(|&seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            return karger(&weighted, &mut rng, 1, 0).unwrap().weight == 1;
+        })
+        .count();
+    assert!(found >= 90, "{}", found);
+}
+
+#[test]
+fn finds_max_flow() {
+    // Directed network whose maximum flow from s to t is 23.
+    let mut graph: Graph<&str, u64> = Graph::new_directed();
+    for (from, to, capacity) in [
+        ("s", "a", 16),
+        ("s", "c", 13),
+        ("a", "b", 12),
+        ("c", "a", 4),
+        ("b", "c", 9),
+        ("c", "d", 14),
+        ("d", "b", 7),
+        ("b", "t", 20),
+        ("d", "t", 4),
+    ] {
+        graph.add_labelled_edge(from, to, capacity);
+    }
+    let s = graph.index_of("s").unwrap();
+    let t = graph.index_of("t").unwrap();
+    for flow in [edmonds_karp(&graph, s, t), dinic(&graph, s, t)] {
+        assert_eq!(flow.value, 23);
+        let cut = flow
+            .cut_edges
+            .iter()
+            .map(|&(from, to)| (*graph.label(from), *graph.label(to)))
+            .collect::<Vec<_>>();
+        assert_eq!(cut, vec![("a", "b"), ("d", "b"), ("d", "t")]);
+        let reachable = flow.residual.reachable(s);
+        assert!(reachable[s] && !reachable[t]);
+        assert_eq!(flow.residual.edges(t).count(), 2);
+    }
+
+    let graph = get_bridged_triangles();
+    let a = graph.index_of("a").unwrap();
+    let f = graph.index_of("f").unwrap();
+    for flow in [edmonds_karp(&graph, a, f), dinic(&graph, a, f)] {
+        assert_eq!(flow.value, 1);
+        assert_eq!(flow.cut_edges.len(), 1);
+    }
+}
+
+#[test]
+fn finds_longest_paths() {
+    // 0 - 1 - 2
+    // |   |   |
+    // 3 - 4 - 5 - 6
+    let mut graph: Graph<usize, usize> = Graph::new_undirected();
+    for (from, to) in [
+        (0, 1),
+        (1, 2),
+        (3, 4),
+        (4, 5),
+        (0, 3),
+        (1, 4),
+        (2, 5),
+        (5, 6),
+    ] {
+        graph.add_labelled_edge(from, to, 1);
+    }
+    let path = longest_path(&graph, 0, 6).unwrap();
+    assert_eq!(path.cost, 6);
+    assert_eq!(path.states, vec![0, 3, 4, 1, 2, 5, 6]);
+    let path = longest_path(&graph, 0, 5).unwrap();
+    assert_eq!(path.cost, 5);
+
+    let mut dag: Graph<&str, usize> = Graph::new_directed();
+    for (from, to, length) in [
+        ("a", "b", 2),
+        ("b", "d", 2),
+        ("a", "c", 1),
+        ("c", "d", 5),
+        ("a", "d", 3),
+    ] {
+        dag.add_labelled_edge(from, to, length);
+    }
+    let a = dag.index_of("a").unwrap();
+    let d = dag.index_of("d").unwrap();
+    let path = longest_path(&dag, a, d).unwrap();
+    assert_eq!(path.cost, 6);
+    let labels = path
+        .states
+        .iter()
+        .map(|&i| *dag.label(i))
+        .collect::<Vec<_>>();
+    assert_eq!(labels, vec!["a", "c", "d"]);
+    assert_eq!(longest_path(&dag, d, a), Option::None);
+}
+
+#[test]
+fn compresses_mazes() {
+    let lines = ["#.###", "#...#", "#.#.#", "#...#", "###.#"];
+    let grid = Grid::from_lines(lines, |c| c);
+    let graph = junction_graph(&grid, &[(1, 0), (3, 4)], |&c, _| c != '#');
+    let labels = graph.indices().map(|i| *graph.label(i)).collect::<Vec<_>>();
+    assert_eq!(labels, vec![(1, 0), (1, 1), (3, 3), (3, 4)]);
+    assert_eq!(graph.edges(1), &[(0, 1), (2, 4), (2, 4)]);
+    assert_eq!(graph.edge_count(), 8);
+    assert_eq!(longest_path(&graph, 0, 3).unwrap().cost, 6);
+
+    // A one-way cell turns the eastern corridor into a dead end going west.
+    let lines = ["#.###", "#.>.#", "#.#.#", "#...#", "###.#"];
+    let grid = Grid::from_lines(lines, |c| c);
+    let graph = junction_graph(&grid, &[(1, 0), (3, 4)], |&c, direction| match c {
+        '#' => false,
+        '>' => direction != Direction4::West,
+        _ => true,
+    });
+    assert_eq!(graph.edges(2), &[(1, 4), (3, 1)]);
+    assert_eq!(graph.edge_count(), 7);
+}
@@ -0,0 +1,60 @@
+extern crate lib;
+
+use itertools::Itertools;
+use lib::grid::Grid;
+
+fn sample() -> Grid<char> {
+    return Grid::from_lines(["abc", "def"], |c| c);
+}
+
+#[test]
+fn parses_rows_and_columns() {
+    let grid = sample();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(grid[(1, 0)], 'b');
+    assert_eq!(grid.get((3, 0)), Option::None);
+    assert_eq!(grid.get_signed((-1, 0)), Option::None);
+    assert_eq!(grid.position(|&c| c == 'e'), Option::Some((1, 1)));
+}
+
+#[test]
+#[should_panic]
+fn rejects_ragged_rows() {
+    Grid::from_lines(["abc", "de"], |c| c);
+}
+
+#[test]
+#[should_panic]
+fn rejects_swaps_out_of_bounds() {
+    // (3, 0) would be (0, 1) in a row-major layout of a 3-wide grid.
+    sample().swap((0, 0), (3, 0));
+}
+
+#[test]
+fn lists_neighbours_inside_bounds() {
+    let grid = sample();
+    assert_eq!(grid.neighbours4((0, 0)).collect_vec(), vec![(0, 1), (1, 0)]);
+    assert_eq!(
+        grid.neighbours4((1, 1)).collect_vec(),
+        vec![(1, 0), (0, 1), (2, 1)]
+    );
+    assert_eq!(
+        grid.neighbours8((0, 1)).collect_vec(),
+        vec![(0, 0), (1, 0), (1, 1)]
+    );
+}
+
+#[test]
+fn transposes_and_rotates() {
+    let grid = sample();
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+    assert_eq!(
+        grid.rotate_clockwise().rotate_counterclockwise(),
+        grid.clone()
+    );
+}
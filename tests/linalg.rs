@@ -0,0 +1,76 @@
+extern crate lib;
+
+use lib::linalg::{solve, SingularMatrix};
+use num::{BigInt, BigRational, Zero};
+
+fn ratio(numerator: i64, denominator: i64) -> BigRational {
+    return BigRational::new(BigInt::from(numerator), BigInt::from(denominator));
+}
+
+fn to_ratios(values: &[i64]) -> Vec<BigRational> {
+    return values.iter().map(|&value| ratio(value, 1)).collect();
+}
+
+#[test]
+fn solves_exactly() {
+    // 3y - 3z = 1, x + 2y + 3z = 1, 2x + 5y + 4z = 3, where the first pivot
+    // has to come from another row.
+    let matrix = vec![
+        to_ratios(&[0, 3, -3]),
+        to_ratios(&[1, 2, 3]),
+        to_ratios(&[2, 5, 4]),
+    ];
+    let vector = to_ratios(&[1, 1, 3]);
+    let solution = solve(&matrix, &vector).unwrap();
+    for row in 0..3 {
+        let value = (0..3)
+            .map(|col| &matrix[row][col] * &solution[col])
+            .fold(BigRational::zero(), |acc, value| acc + value);
+        assert_eq!(value, vector[row]);
+    }
+    assert_eq!(solution, vec![ratio(11, 3), ratio(-1, 3), ratio(-2, 3)]);
+
+    // Values that would lose precision as f64.
+    let big: BigInt = BigInt::from(10).pow(20) + 1;
+    let matrix = vec![
+        vec![BigRational::from(big.clone()), ratio(1, 1)],
+        vec![ratio(1, 1), ratio(0, 1)],
+    ];
+    let vector = vec![BigRational::from(big.clone() * 3 + 1), ratio(3, 1)];
+    let solution = solve(&matrix, &vector).unwrap();
+    assert_eq!(solution, vec![ratio(3, 1), ratio(1, 1)]);
+}
+
+#[test]
+fn solves_integer_systems_as_ratios() {
+    // 2x + 4y = 3, 4x + 2y = 3 has no integer solution, which truncating
+    // integer division would silently turn into x = 1, y = 0.
+    let matrix = vec![to_ratios(&[2, 4]), to_ratios(&[4, 2])];
+    let vector = to_ratios(&[3, 3]);
+    assert_eq!(
+        solve(&matrix, &vector),
+        Result::Ok(vec![ratio(1, 2), ratio(1, 2)])
+    );
+}
+
+#[test]
+fn solves_without_overflowing() {
+    // Coefficients that fit in an i64, but whose products during the
+    // elimination don't, as in day 24 part 2.
+    let large = 1 << 40;
+    let matrix = vec![
+        to_ratios(&[large, 3, 1]),
+        to_ratios(&[7, large + 1, 5]),
+        to_ratios(&[11, 13, large - 1]),
+    ];
+    let vector = to_ratios(&[large + 4, large + 13, large + 23]);
+    let solution = solve(&matrix, &vector).unwrap();
+    assert_eq!(solution, to_ratios(&[1, 1, 1]));
+}
+
+#[test]
+fn reports_singular_systems() {
+    let matrix = vec![to_ratios(&[1, 2]), to_ratios(&[2, 4])];
+    let vector = to_ratios(&[3, 6]);
+    assert_eq!(solve(&matrix, &vector), Result::Err(SingularMatrix));
+}
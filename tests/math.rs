@@ -0,0 +1,269 @@
+extern crate lib;
+
+use lib::checked::Overflow;
+use lib::math::poly::{extrapolate_backward, extrapolate_forward, Polynomial};
+use lib::math::{
+    count_products_above, crt, extended_gcd, first_common_time, isqrt, Congruence, Sequence,
+};
+use num::{BigInt, BigRational};
+
+#[test]
+fn computes_bezout_coefficients() {
+    for (a, b, gcd) in [
+        (240i64, 46, 2),
+        (-12, 18, 6),
+        (7, 0, 7),
+        (0, -5, 5),
+        (17, 5, 1),
+    ] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(g, gcd);
+        assert_eq!(a * x + b * y, g);
+    }
+}
+
+#[test]
+fn solves_congruences() {
+    // Coprime moduli
+    assert_eq!(
+        crt(&[
+            Congruence::new(2, 3),
+            Congruence::new(3, 5),
+            Congruence::new(2, 7)
+        ]),
+        Result::Ok(Option::Some(Congruence::new(23, 105)))
+    );
+    // Moduli with common factors
+    assert_eq!(
+        crt(&[Congruence::new(3, 4), Congruence::new(5, 6)]),
+        Result::Ok(Option::Some(Congruence::new(11, 12)))
+    );
+    assert_eq!(
+        crt(&[Congruence::new(1, 4), Congruence::new(2, 6)]),
+        Result::Ok(Option::None)
+    );
+    assert_eq!(crt(&[]), Result::Ok(Option::Some(Congruence::new(0, 1))));
+    // The LCM of the moduli does not fit in a u64.
+    assert_eq!(
+        crt(&[
+            Congruence::new(1, 4294967311),
+            Congruence::new(2, 4294967357)
+        ]),
+        Result::Err(Overflow)
+    );
+}
+
+#[test]
+fn finds_first_common_time() {
+    // Times 2, 5, 8, ... and 1, 5, 9, ...
+    assert_eq!(
+        first_common_time(&[
+            Sequence {
+                offset: 2,
+                period: 3
+            },
+            Sequence {
+                offset: 1,
+                period: 4
+            }
+        ]),
+        Result::Ok(Option::Some(5))
+    );
+    // The congruence is satisfied by 4, but the second sequence starts later.
+    assert_eq!(
+        first_common_time(&[
+            Sequence {
+                offset: 4,
+                period: 6
+            },
+            Sequence {
+                offset: 16,
+                period: 4
+            }
+        ]),
+        Result::Ok(Option::Some(16))
+    );
+    // Single times
+    assert_eq!(
+        first_common_time(&[
+            Sequence {
+                offset: 7,
+                period: 0
+            },
+            Sequence {
+                offset: 1,
+                period: 3
+            }
+        ]),
+        Result::Ok(Option::Some(7))
+    );
+    assert_eq!(
+        first_common_time(&[
+            Sequence {
+                offset: 8,
+                period: 0
+            },
+            Sequence {
+                offset: 1,
+                period: 3
+            }
+        ]),
+        Result::Ok(Option::None)
+    );
+    assert_eq!(
+        first_common_time(&[
+            Sequence {
+                offset: 0,
+                period: 2
+            },
+            Sequence {
+                offset: 1,
+                period: 2
+            }
+        ]),
+        Result::Ok(Option::None)
+    );
+}
+
+#[test]
+fn intersects_sequences() {
+    let sequence = |offset, period| Sequence { offset, period };
+    // Times 2, 5, 8, ... and 1, 5, 9, ...
+    assert_eq!(
+        sequence(2, 3).intersect(&sequence(1, 4)),
+        Result::Ok(Option::Some(sequence(5, 12)))
+    );
+    assert_eq!(
+        sequence(4, 6).intersect(&sequence(16, 4)),
+        Result::Ok(Option::Some(sequence(16, 12)))
+    );
+    assert_eq!(
+        sequence(7, 0).intersect(&sequence(1, 3)),
+        Result::Ok(Option::Some(sequence(7, 0)))
+    );
+    assert_eq!(
+        sequence(0, 2).intersect(&sequence(1, 2)),
+        Result::Ok(Option::None)
+    );
+    assert_eq!(
+        sequence(u64::MAX - 1, 2).intersect(&sequence(1, 3)),
+        Result::Err(Overflow)
+    );
+
+    assert!(sequence(5, 12).is_subset(&sequence(1, 4)));
+    assert!(sequence(9, 0).is_subset(&sequence(1, 4)));
+    assert!(!sequence(1, 4).is_subset(&sequence(5, 4)));
+    assert!(!sequence(5, 6).is_subset(&sequence(1, 4)));
+    assert!(!sequence(5, 4).is_subset(&sequence(5, 0)));
+}
+
+fn to_rational(numerator: i64, denominator: i64) -> BigRational {
+    return BigRational::new(BigInt::from(numerator), BigInt::from(denominator));
+}
+
+#[test]
+fn extrapolates_sequences() {
+    let values = vec![10, 13, 16, 21, 30, 45];
+    assert_eq!(extrapolate_forward(&values).get(), Result::Ok(68));
+    assert_eq!(extrapolate_backward(&values).get(), Result::Ok(5));
+    // Constant and empty sequences
+    assert_eq!(extrapolate_forward(&vec![7, 7, 7]).get(), Result::Ok(7));
+    assert_eq!(extrapolate_backward(&vec![0, 0]).get(), Result::Ok(0));
+    assert_eq!(extrapolate_forward(&vec![]).get(), Result::Ok(0));
+    // A single value is taken as a constant.
+    assert_eq!(extrapolate_backward(&vec![3]).get(), Result::Ok(3));
+
+    // Differences that don't fit
+    let values = vec![i64::MIN, i64::MAX];
+    assert_eq!(extrapolate_forward(&values).get(), Result::Err(Overflow));
+    // Differences that fit, but a next value that doesn't
+    let values = vec![0, i64::MAX];
+    assert_eq!(extrapolate_forward(&values).get(), Result::Err(Overflow));
+    assert_eq!(extrapolate_backward(&values).get(), Result::Ok(-i64::MAX));
+    let values = vec![i64::MAX, 0];
+    assert_eq!(extrapolate_backward(&values).get(), Result::Err(Overflow));
+}
+
+#[test]
+fn interpolates_points() {
+    // 1/2 * x^2 - 3 * x + 4
+    let polynomial = Polynomial::interpolate(&vec![(-2, 12), (0, 4), (4, 0)]);
+    assert_eq!(polynomial.degree(), Option::Some(2));
+    assert_eq!(
+        polynomial.coefficients(),
+        &vec![to_rational(4, 1), to_rational(-3, 1), to_rational(1, 2)]
+    );
+    assert_eq!(polynomial.evaluate(&to_rational(1, 1)), to_rational(3, 2));
+    assert_eq!(polynomial.evaluate(&to_rational(10, 1)), to_rational(24, 1));
+
+    // Collinear points give a line.
+    let polynomial = Polynomial::interpolate(&vec![(0, 1), (1, 3), (2, 5)]);
+    assert_eq!(polynomial.degree(), Option::Some(1));
+    let polynomial = Polynomial::interpolate(&vec![(0, 0), (5, 0)]);
+    assert_eq!(polynomial.degree(), Option::None);
+}
+
+#[test]
+fn fits_polynomials() {
+    // x^2 + x + 1
+    let points = vec![(0, 1), (1, 3), (2, 7), (3, 13), (5, 31)];
+    let polynomial = Polynomial::fit(&points, 2).unwrap();
+    assert_eq!(
+        polynomial.evaluate(&to_rational(100, 1)),
+        to_rational(10101, 1)
+    );
+    assert_eq!(Polynomial::fit(&points, 1), Option::None);
+    assert_eq!(Polynomial::fit(&points, 3), Option::Some(polynomial));
+}
+
+#[test]
+fn computes_integer_square_roots() {
+    for n in 0..1000u128 {
+        let root = isqrt(n);
+        assert!(root * root <= n && (root + 1) * (root + 1) > n, "{}", n);
+    }
+    let root = u64::MAX as u128;
+    assert_eq!(isqrt(root * root), root);
+    assert_eq!(isqrt(root * root - 1), root - 1);
+    assert_eq!(isqrt(u128::MAX), root);
+}
+
+fn count_naively(sum: u64, threshold: u64) -> u64 {
+    return (0..=sum).filter(|x| x * (sum - x) > threshold).count() as u64;
+}
+
+#[test]
+fn counts_products_above_threshold() {
+    assert_eq!(count_products_above(7, 9), 4);
+    assert_eq!(count_products_above(15, 40), 8);
+    assert_eq!(count_products_above(30, 200), 9);
+    assert_eq!(count_products_above(71530, 940200), 71503);
+    for sum in 0..40 {
+        for threshold in 0..=(sum * sum / 4 + 1) {
+            assert_eq!(
+                count_products_above(sum, threshold),
+                count_naively(sum, threshold),
+                "{} {}",
+                sum,
+                threshold
+            );
+        }
+    }
+}
+
+#[test]
+fn excludes_products_equal_to_threshold() {
+    // The discriminant is a perfect square, so the roots are integers: 2 * 5
+    // only ties the threshold.
+    assert_eq!(count_products_above(7, 10), 2);
+    assert_eq!(count_products_above(7, 12), 0);
+    // 3 * 3 is the largest product, and ties it.
+    assert_eq!(count_products_above(6, 9), 0);
+    assert_eq!(count_products_above(6, 8), 1);
+    // Large enough for `f64` square roots to be off.
+    let sum = 1 << 32;
+    let root = (1u64 << 20) + 7;
+    let threshold = (sum / 2 - root) * (sum / 2 + root);
+    assert_eq!(count_products_above(sum, threshold), 2 * root - 1);
+    assert_eq!(count_products_above(sum, threshold - 1), 2 * root + 1);
+}
@@ -0,0 +1,61 @@
+extern crate lib;
+
+use lib::reader::{parse_grid, Input, ReaderError};
+use lib::registry::get_entries;
+use lib::solution::{solve, Answer, Part, SolveError};
+use lib::y2023::day01::Day01;
+use lib::y2023::day05::Day05;
+
+#[test]
+fn reports_malformed_input() {
+    let input = Input::Text("garbage\nfoo\n".to_owned());
+    for entry in get_entries() {
+        let label = format!("{}/{:02}", entry.year, entry.day);
+        let result = (entry.solve)(&input, &[], &[Part::One, Part::Two]);
+        assert!(
+            matches!(
+                result,
+                Result::Err(SolveError::Reader(ReaderError::Malformed { .. }))
+            ),
+            "{}",
+            label
+        );
+    }
+}
+
+#[test]
+fn points_at_malformed_lines() {
+    let input = Input::Text(String::new());
+    let lines = vec!["#.".to_owned(), "..".to_owned(), "#".to_owned()];
+    let is_wall = |c| match c {
+        '#' => Option::Some(true),
+        '.' => Option::Some(false),
+        _ => Option::None,
+    };
+    let grid = parse_grid(&input, 4, &lines[..2], is_wall).unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+    assert!(grid[(0, 0)] && !grid[(1, 1)]);
+
+    let error = parse_grid(&input, 4, &lines, is_wall).unwrap_err();
+    assert!(matches!(error, ReaderError::Malformed { line: 6, .. }));
+    let lines = vec!["#x".to_owned()];
+    let error = parse_grid(&input, 1, &lines, is_wall).unwrap_err();
+    assert_eq!(error.to_string(), "<text>:1: unexpected character `x`");
+    let error = parse_grid(&input, 1, &[], is_wall).unwrap_err();
+    assert!(matches!(error, ReaderError::Malformed { line: 1, .. }));
+}
+
+#[test]
+fn answers_the_parts_an_input_supports() {
+    let input = Input::Text("one2\ntwo\n".to_owned());
+    let run = solve::<Day01>(&input, &[], &[Part::One, Part::Two]).unwrap();
+    assert_eq!(run.answers, vec![Answer::Empty, Answer::Integer(34)]);
+    assert_eq!(
+        run.details[0],
+        vec![("no digits".to_owned(), "line 2".to_owned())]
+    );
+
+    let input = Input::Text("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n".to_owned());
+    let run = solve::<Day05>(&input, &[], &[Part::One, Part::Two]).unwrap();
+    assert_eq!(run.answers, vec![Answer::Integer(14), Answer::Empty]);
+}
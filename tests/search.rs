@@ -0,0 +1,77 @@
+extern crate lib;
+
+use lib::grid::Grid;
+use lib::search::{astar, bfs, dijkstra};
+
+/// Weighted digraph: 0 -> 1 (4), 0 -> 2 (1), 2 -> 1 (2), 1 -> 3 (1), 2 -> 3 (5).
+fn edges(node: &usize) -> Vec<(usize, u32)> {
+    return match node {
+        0 => vec![(1, 4), (2, 1)],
+        1 => vec![(3, 1)],
+        2 => vec![(1, 2), (3, 5)],
+        _ => vec![],
+    };
+}
+
+#[test]
+fn finds_cheapest_path() {
+    let path = dijkstra([0], edges, |&node| node == 3).unwrap();
+    assert_eq!(path.cost, 4);
+    assert_eq!(path.states, vec![0, 2, 1, 3]);
+    assert_eq!(dijkstra([0], edges, |&node| node == 4), Option::None);
+}
+
+#[test]
+fn starts_from_any_source() {
+    let path = dijkstra([0, 1], edges, |&node| node == 3).unwrap();
+    assert_eq!(path.cost, 1);
+    assert_eq!(*path.start(), 1);
+}
+
+#[test]
+fn astar_matches_dijkstra_on_grid() {
+    let grid: Grid<u32> = Grid::from_lines(["1163", "1381", "2136"], |c| c.to_digit(10).unwrap());
+    let target = (grid.width() - 1, grid.height() - 1);
+    let neighbours = |&position: &(usize, usize)| {
+        return grid
+            .neighbours4(position)
+            .map(|next| (next, grid[next]))
+            .collect::<Vec<_>>();
+    };
+    let expected = dijkstra([(0, 0)], neighbours, |&position| position == target).unwrap();
+    let path = astar(
+        [(0, 0)],
+        neighbours,
+        |&(x, y)| ((target.0 - x) + (target.1 - y)) as u32,
+        |&position| position == target,
+    )
+    .unwrap();
+    assert_eq!(path.cost, expected.cost);
+    assert_eq!(*path.goal(), target);
+}
+
+#[test]
+fn maps_grid_distances() {
+    let grid: Grid<bool> = Grid::from_lines(["...", ".#.", "..#"], |c| c == '.');
+    let distances = bfs::grid_distances(&grid, [(0, 0)], |&open| open);
+    assert_eq!(distances[(2, 1)], Option::Some(3));
+    assert_eq!(distances[(1, 2)], Option::Some(3));
+    assert_eq!(distances[(1, 1)], Option::None);
+    assert_eq!(distances[(2, 2)], Option::None);
+
+    let reachable = || distances.iter().flatten().copied();
+    assert_eq!(bfs::count_at_most(reachable(), 2), 5);
+    assert_eq!(bfs::count_exactly(reachable(), 2), 3);
+    assert_eq!(bfs::count_exactly(reachable(), 3), 4);
+    assert_eq!(bfs::count_by_parity(reachable()), [3, 4]);
+}
+
+#[test]
+fn maps_graph_distances_from_many_sources() {
+    let distances = bfs::distances([0, 3], |node: &usize| {
+        return edges(node).into_iter().map(|(next, _)| next);
+    });
+    assert_eq!(distances[&0], 0);
+    assert_eq!(distances[&1], 1);
+    assert_eq!(distances[&3], 0);
+}